@@ -0,0 +1,49 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// collects all solutions in `src/bin/` (files named `<year>_<day>.rs`, e.g. `2022_07.rs`) and
+/// generates the registry that the `advent_of_code` binary uses to run every day in-process.
+fn main() {
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut puzzles = fs::read_dir(&bin_dir)
+        .expect("could not read src/bin")
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name().into_string().ok()?;
+            let (year, day) = file_name.strip_suffix(".rs")?.split_once('_')?;
+            let is_puzzle = year.len() == 4
+                && year.parse::<u16>().is_ok()
+                && day.len() == 2
+                && day.parse::<u8>().is_ok();
+            is_puzzle.then(|| format!("{}_{}", year, day))
+        })
+        .collect::<Vec<_>>();
+    puzzles.sort();
+
+    let mut registry = String::new();
+    for puzzle in &puzzles {
+        registry.push_str(&format!(
+            "#[cfg(not(test))]\n#[allow(dead_code)]\n#[path = {:?}]\nmod y{};\n",
+            bin_dir.join(format!("{}.rs", puzzle)).display().to_string(),
+            puzzle
+        ));
+    }
+
+    // solutions are tested in their own binaries, the registry is left empty under `cargo test`.
+    registry
+        .push_str("#[cfg(not(test))]\npub const SOLUTIONS: &[advent_of_code::runner::Day] = &[\n");
+    for puzzle in &puzzles {
+        registry.push_str(&format!("    y{}::SOLUTION,\n", puzzle));
+    }
+    registry
+        .push_str("];\n#[cfg(test)]\npub const SOLUTIONS: &[advent_of_code::runner::Day] = &[];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, registry).expect("could not write solution registry");
+}
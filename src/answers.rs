@@ -0,0 +1,135 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::{self, InputError};
+use crate::Answer;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// outcome of comparing an answer against the accepted answer in `src/<year>/answers/NN.txt`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "✅",
+            Verdict::Wrong { .. } => "❌",
+            Verdict::Unknown => "❓",
+        }
+    }
+}
+
+pub fn answers_path(year: i16, day: u8) -> Result<PathBuf, InputError> {
+    input::puzzle_path("answers", year, day)
+}
+
+/// the answer file holds the accepted answer of part one on its first line and the one of
+/// part two on its second line. empty or missing lines mean that the answer is not known yet.
+pub fn parse_answers(contents: &str) -> [Option<String>; 2] {
+    let mut lines = contents.lines().map(|line| {
+        let line = line.trim();
+        (!line.is_empty()).then(|| line.to_string())
+    });
+    [lines.next().flatten(), lines.next().flatten()]
+}
+
+pub fn expected_answer(year: i16, day: u8, part: u8) -> Option<String> {
+    let contents = fs::read_to_string(answers_path(year, day).ok()?).ok()?;
+    let [part_one, part_two] = parse_answers(&contents);
+    match part {
+        1 => part_one,
+        2 => part_two,
+        _ => None,
+    }
+}
+
+/// replaces the answer of `part` in the contents of an answer file, keeping the other part.
+pub fn set_answer(contents: &str, part: u8, answer: &str) -> String {
+    let [part_one, part_two] = parse_answers(contents);
+    let (part_one, part_two) = match part {
+        1 => (Some(answer.to_string()), part_two),
+        2 => (part_one, Some(answer.to_string())),
+        _ => panic!("invalid part: {}", part),
+    };
+    format!(
+        "{}\n{}\n",
+        part_one.unwrap_or_default(),
+        part_two.unwrap_or_default()
+    )
+}
+
+/// stores an accepted answer in `src/<year>/answers/NN.txt`.
+pub fn record_answer(
+    year: i16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<PathBuf, std::io::Error> {
+    let path = answers_path(year, day).map_err(std::io::Error::other)?;
+    let contents = fs::read_to_string(&path).unwrap_or_default();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, set_answer(&contents, part, answer))?;
+    Ok(path)
+}
+
+pub fn verdict(answer: Option<&Answer>, expected: Option<String>) -> Verdict {
+    match (answer, expected) {
+        (_, None) => Verdict::Unknown,
+        (Some(answer), Some(expected)) if answer.matches(&expected) => Verdict::Correct,
+        (_, Some(expected)) => Verdict::Wrong { expected },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(
+            parse_answers("24000\n45000\n"),
+            [Some("24000".into()), Some("45000".into())]
+        );
+        assert_eq!(parse_answers("CMZ\n"), [Some("CMZ".into()), None]);
+        assert_eq!(parse_answers("\n 45000 \n"), [None, Some("45000".into())]);
+        assert_eq!(parse_answers(""), [None, None]);
+    }
+
+    #[test]
+    fn test_set_answer() {
+        assert_eq!(set_answer("", 1, "24000"), "24000\n\n");
+        assert_eq!(set_answer("24000\n", 2, "45000"), "24000\n45000\n");
+        assert_eq!(set_answer("1\n45000\n", 1, "24000"), "24000\n45000\n");
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(
+            verdict(Some(&13.into()), Some("13".into())),
+            Verdict::Correct
+        );
+        assert_eq!(
+            verdict(Some(&12.into()), Some("13".into())),
+            Verdict::Wrong {
+                expected: "13".into()
+            }
+        );
+        assert_eq!(
+            verdict(None, Some("13".into())),
+            Verdict::Wrong {
+                expected: "13".into()
+            }
+        );
+        assert_eq!(verdict(Some(&13.into()), None), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,135 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::time::{Duration, Instant};
+
+pub const BENCH_FLAG: &str = "--bench";
+
+/// solvers are called repeatedly for at least this long before sampling starts.
+const WARMUP_TIME: Duration = Duration::from_millis(100);
+/// time budget for the measured samples of one part.
+const TARGET_TIME: Duration = Duration::from_secs(1);
+/// slow solvers (e.g. seconds per run) still get a few samples to compute a median from.
+const MIN_SAMPLES: u64 = 5;
+const MAX_SAMPLES: u64 = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchStats {
+    pub samples: u64,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl BenchStats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample");
+
+        let mut nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        nanos.sort_by(|a, b| a.total_cmp(b));
+
+        let n = nanos.len();
+        let median = if n % 2 == 0 {
+            (nanos[n / 2 - 1] + nanos[n / 2]) / 2_f64
+        } else {
+            nanos[n / 2]
+        };
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        BenchStats {
+            samples: n as u64,
+            min_ns: nanos[0] as u64,
+            median_ns: median.round() as u64,
+            mean_ns: mean.round() as u64,
+            stddev_ns: variance.sqrt().round() as u64,
+        }
+    }
+
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn mean(&self) -> Duration {
+        Duration::from_nanos(self.mean_ns)
+    }
+
+    pub fn stddev(&self) -> Duration {
+        Duration::from_nanos(self.stddev_ns)
+    }
+}
+
+pub fn bench_mode() -> bool {
+    env::args().skip(1).any(|arg| arg == BENCH_FLAG)
+}
+
+/// number of samples that fits into `TARGET_TIME`, given the estimated duration of one run.
+fn sample_count(estimate: Duration) -> u64 {
+    let estimate = estimate.as_nanos().max(1);
+    let count = TARGET_TIME.as_nanos() / estimate;
+    (count as u64).clamp(MIN_SAMPLES, MAX_SAMPLES)
+}
+
+/// runs `func` until warmed up, then samples it repeatedly.
+/// returns the result of the first call alongside the timing statistics.
+pub fn bench<T>(mut func: impl FnMut() -> T) -> (T, BenchStats) {
+    let warmup = Instant::now();
+    let result = func();
+    let mut warmup_runs = 1;
+    while warmup.elapsed() < WARMUP_TIME {
+        func();
+        warmup_runs += 1;
+    }
+    let estimate = warmup.elapsed() / warmup_runs;
+
+    let samples = (0..sample_count(estimate))
+        .map(|_| {
+            let timer = Instant::now();
+            func();
+            timer.elapsed()
+        })
+        .collect::<Vec<_>>();
+
+    (result, BenchStats::from_samples(&samples))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_samples() {
+        let samples = [4, 1, 3, 2, 5].map(Duration::from_micros);
+        let stats = BenchStats::from_samples(&samples);
+
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min(), Duration::from_micros(1));
+        assert_eq!(stats.median(), Duration::from_micros(3));
+        assert_eq!(stats.mean(), Duration::from_micros(3));
+        assert_eq!(stats.stddev_ns, 1414);
+    }
+
+    #[test]
+    fn test_from_samples_even_median() {
+        let samples = [10, 40, 20, 30].map(Duration::from_nanos);
+        assert_eq!(BenchStats::from_samples(&samples).median_ns, 25);
+    }
+
+    #[test]
+    fn test_sample_count() {
+        assert_eq!(sample_count(Duration::from_nanos(20)), MAX_SAMPLES);
+        assert_eq!(sample_count(Duration::from_millis(10)), 100);
+        assert_eq!(sample_count(Duration::from_secs(3)), MIN_SAMPLES);
+    }
+}
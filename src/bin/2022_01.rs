@@ -36,11 +36,7 @@ pub fn part_two(input: &str) -> Option<u32> {
         .into()
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2022, 1);
 
 #[cfg(test)]
 mod tests {
@@ -48,13 +44,11 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
-        assert_eq!(part_one(&input), Some(24000));
+        advent_of_code::examples::check(YEAR, DAY, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
-        assert_eq!(part_two(&input), Some(45000));
+        advent_of_code::examples::check(YEAR, DAY, 2, part_two);
     }
 }
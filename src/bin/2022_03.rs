@@ -58,11 +58,7 @@ pub fn part_two(input: &str) -> Option<u32> {
         .into()
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2022, 3);
 
 #[cfg(test)]
 mod tests {
@@ -70,13 +66,11 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_one(&input), Some(157));
+        advent_of_code::examples::check(YEAR, DAY, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_two(&input), Some(70));
+        advent_of_code::examples::check(YEAR, DAY, 2, part_two);
     }
 }
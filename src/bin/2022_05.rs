@@ -117,11 +117,7 @@ pub fn part_two(input: &str) -> Option<String> {
     execute_crane_movement(input, true)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2022, 5);
 
 #[cfg(test)]
 mod tests {
@@ -129,13 +125,11 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_one(&input), Some("CMZ".to_string()));
+        advent_of_code::examples::check(YEAR, DAY, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_two(&input), Some("MCD".to_string()));
+        advent_of_code::examples::check(YEAR, DAY, 2, part_two);
     }
 }
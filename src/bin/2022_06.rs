@@ -30,11 +30,7 @@ pub fn part_two(input: &str) -> Option<u32> {
     detect_starter_by_count(input, 14)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2022, 6);
 
 #[cfg(test)]
 mod tests {
@@ -42,13 +38,11 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_one(&input), Some(5));
+        advent_of_code::examples::check(YEAR, DAY, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_two(&input), Some(23));
+        advent_of_code::examples::check(YEAR, DAY, 2, part_two);
     }
 }
@@ -1,4 +1,5 @@
 use std::cmp::min;
+use advent_of_code::{ParseError, Params, Solution};
 use indextree::{Arena, NodeId};
 
 const MAX_DIR_DIM: u64 = 100000_u64;
@@ -32,24 +33,28 @@ fn compute_size_part_one(final_size: &mut u64, node: NodeId, arena: &Arena<NodeD
     size
 }
 
-fn compute_arena_tree(input: &str) -> (NodeId, Arena<NodeData>) {
+fn compute_arena_tree(input: &str) -> Result<(NodeId, Arena<NodeData>), ParseError> {
     let arena: &mut Arena<NodeData> = &mut Arena::new();
     let mut pwd_node: Option<NodeId> = None;
     let mut root_node: Option<NodeId> = None;
 
-    for line in input.lines() {
-        let first_char = line.chars().next().unwrap();
+    for (index, line) in input.lines().enumerate() {
+        let error = |message: &str| ParseError::new(format!("{}: `{}`", message, line)).at_line(index);
+        let first_char = line.chars().next().ok_or_else(|| error("empty line"))?;
 
         match first_char {
             '$' => {
                 let command_parts = line.split_whitespace().collect::<Vec<&str>>();
-                let command_name = command_parts[1];
+                let command_name = *command_parts.get(1).ok_or_else(|| error("missing command"))?;
                 match command_name {
                     "cd" => {
-                        let command_arg_file_name = command_parts[2];
+                        let command_arg_file_name = *command_parts.get(2).ok_or_else(|| error("missing directory"))?;
                         match command_arg_file_name {
                             ".." => {
-                                pwd_node = pwd_node.unwrap().ancestors(arena).nth(1);
+                                pwd_node = pwd_node
+                                    .ok_or_else(|| error("no directory to leave"))?
+                                    .ancestors(arena)
+                                    .nth(1);
                             }
                             file_name => {
                                 match pwd_node {
@@ -79,16 +84,19 @@ fn compute_arena_tree(input: &str) -> (NodeId, Arena<NodeData>) {
                         }
                     }
                     "ls" => continue,
-                    _ => panic!("Unknown command: {}", command_name)
+                    _ => return Err(error("unknown command"))
                 }
             }
             _ => {
                 let output_parts = line.split_whitespace().collect::<Vec<&str>>();
-                let (first_part, file_name) = (output_parts[0], output_parts[1]);
+                let (first_part, file_name) = match output_parts[..] {
+                    [first_part, file_name] => (first_part, file_name),
+                    _ => return Err(error("expected `dir <name>` or `<size> <name>`")),
+                };
+                let pwd = pwd_node.ok_or_else(|| error("listing outside of a directory"))?;
                 match first_part {
                     "dir" => {
-                        pwd_node
-                            .unwrap()
+                        pwd
                             .append(
                                 arena.new_node(
                                     NodeData {
@@ -98,9 +106,8 @@ fn compute_arena_tree(input: &str) -> (NodeId, Arena<NodeData>) {
                                     }), arena);
                     }
                     size => {
-                        let file_size = size.parse::<u64>().unwrap();
-                        pwd_node
-                            .unwrap()
+                        let file_size = size.parse::<u64>().map_err(|_| error("invalid file size"))?;
+                        pwd
                             .append(
                                 arena.new_node(
                                     NodeData {
@@ -114,19 +121,19 @@ fn compute_arena_tree(input: &str) -> (NodeId, Arena<NodeData>) {
         }
     }
 
-    (root_node.unwrap().to_owned(), arena.clone())
-}
-
-pub fn part_one(input: &str) -> Option<u64> {
-    let (root_node, arena) = compute_arena_tree(input);
-    let mut final_size = 0_u64;
-    compute_size_part_one(&mut final_size, root_node, &arena);
-    final_size.into()
+    let root_node = root_node.ok_or_else(|| ParseError::new("no root directory"))?;
+    Ok((root_node, arena.clone()))
 }
 
+// defaults of the `TOTAL_SIZE` and `UPDATE_SIZE` parameters.
 const TOTAL_SIZE: u64 = 70000000_u64;
 const UPDATE_SIZE: u64 = 30000000_u64;
 
+struct Disk {
+    pub total_size: u64,
+    pub update_size: u64,
+}
+
 fn compute_size_part_two(node: NodeId, arena: &mut Arena<NodeData>) -> u64 {
     let mut size = 0;
     for child in node.children(&arena.to_owned()) {
@@ -150,36 +157,74 @@ fn compute_size_of_dir_to_delete(final_size: &mut u64, lower_bound: u64, node: N
     }
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let (root_node, mut arena) = compute_arena_tree(input);
-    let total_used_size = compute_size_part_two(root_node, &mut arena);
-    let total_free_size = TOTAL_SIZE - total_used_size;
-    let update_extra_necessary_size = UPDATE_SIZE - total_free_size;
+struct Day07;
 
-    let mut final_size = TOTAL_SIZE;
-    compute_size_of_dir_to_delete(&mut final_size, update_extra_necessary_size, root_node, &arena);
-    final_size.into()
-}
+impl Solution for Day07 {
+    type Parsed = (NodeId, Arena<NodeData>, Disk);
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        let (root_node, arena) = compute_arena_tree(input)?;
+        let disk = Disk {
+            total_size: params.get_or("TOTAL_SIZE", TOTAL_SIZE)?,
+            update_size: params.get_or("UPDATE_SIZE", UPDATE_SIZE)?,
+        };
+        Ok((root_node, arena, disk))
+    }
+
+    fn part_one((root_node, arena, _): &Self::Parsed) -> Option<u64> {
+        let mut final_size = 0_u64;
+        compute_size_part_one(&mut final_size, *root_node, arena);
+        final_size.into()
+    }
+
+    fn part_two((root_node, arena, disk): &Self::Parsed) -> Option<u64> {
+        // sizes are accumulated in place, so work on a copy of the tree.
+        let mut arena = arena.clone();
+        let total_used_size = compute_size_part_two(*root_node, &mut arena);
+        let total_free_size = disk.total_size.checked_sub(total_used_size)?;
+        let update_extra_necessary_size = disk.update_size.saturating_sub(total_free_size);
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+        let mut final_size = disk.total_size;
+        compute_size_of_dir_to_delete(&mut final_size, update_extra_necessary_size, *root_node, &arena);
+        final_size.into()
+    }
 }
 
+advent_of_code::solution!(2022, 7, Day07);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_one(&input), Some(95437));
+        advent_of_code::examples::check_solution::<Day07>(YEAR, DAY, 1);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_two(&input), Some(24933642));
+        advent_of_code::examples::check_solution::<Day07>(YEAR, DAY, 2);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day07::parse("$ cd /\n$ rm -rf /", &Params::new()).err().unwrap();
+        assert_eq!(error.to_string(), "line 2: unknown command: `$ rm -rf /`");
+    }
+
+    #[test]
+    fn test_disk_params() {
+        let input = advent_of_code::read_file("examples", YEAR, DAY);
+
+        // 48381165 are used. 4000000 missing bytes need `d` to be deleted, 584 only need `e`.
+        let params = Params::new().with("TOTAL_SIZE", 60000000).with("UPDATE_SIZE", 15618835);
+        let parsed = Day07::parse(&input, &params).unwrap();
+        assert_eq!(Day07::part_two(&parsed), Some(24933642));
+
+        let params = Params::new().with("UPDATE_SIZE", 21619419);
+        let parsed = Day07::parse(&input, &params).unwrap();
+        assert_eq!(Day07::part_two(&parsed), Some(584));
     }
 }
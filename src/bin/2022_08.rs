@@ -0,0 +1,63 @@
+use advent_of_code::helpers::grid::{Grid, DIRECTIONS4};
+
+fn get_tree_grid(input: &str) -> Grid<u32> {
+    Grid::parse_with(input, |c| c.to_digit(10)).unwrap()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let tree_grid = get_tree_grid(input);
+
+    // trees on the edge have an empty ray towards it, so they are always visible.
+    let visible_trees = tree_grid
+        .iter()
+        .filter(|&(pos, tree)| {
+            DIRECTIONS4.into_iter().any(|direction| {
+                tree_grid
+                    .ray(pos, direction)
+                    .all(|other| tree_grid[other] < *tree)
+            })
+        })
+        .count();
+
+    Some(visible_trees as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let tree_grid = get_tree_grid(input);
+
+    tree_grid
+        .iter()
+        .map(|(pos, tree)| {
+            DIRECTIONS4
+                .into_iter()
+                .map(|direction| {
+                    let mut viewing_distance = 0u32;
+                    for other in tree_grid.ray(pos, direction) {
+                        viewing_distance += 1;
+                        if tree_grid[other] >= *tree {
+                            break;
+                        }
+                    }
+                    viewing_distance
+                })
+                .product()
+        })
+        .max()
+}
+
+advent_of_code::solution!(2022, 8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check(YEAR, DAY, 1, part_one);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check(YEAR, DAY, 2, part_two);
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use advent_of_code::visualize::{self, Frame};
 use phf::{Map, phf_map};
 
 const DIRECTION_TO_DELTA: Map<&str, (isize, isize)> = phf_map! {
@@ -60,11 +61,19 @@ impl MatrixPoint {
     }
 }
 
+/// the positions visited by the tail as `#`, and the knots from the tail up to the head.
+fn rope_frame(visited: &HashSet<MatrixPoint>, knots: &[(MatrixPoint, char)]) -> Frame {
+    let visited = visited.iter().map(|point| ((point.x, point.y), '#'));
+    let knots = knots.iter().rev().map(|(point, label)| ((point.x, point.y), *label));
+    Frame::from_points(visited.chain(knots))
+}
+
 pub fn part_one(input: &str) -> Option<u32> {
     let mut head = MatrixPoint::new(1, 1);
     let mut tail = MatrixPoint::new(1, 1);
 
     let mut motion_matrix: HashSet<MatrixPoint> = HashSet::new();
+    motion_matrix.insert(tail);
 
     input
         .lines()
@@ -77,12 +86,14 @@ pub fn part_one(input: &str) -> Option<u32> {
         .for_each(|(direction, distance)| {
             let delta = DIRECTION_TO_DELTA[direction];
             for _ in 0..distance {
-                motion_matrix.insert(tail);
                 head.update_position_by_delta(delta);
                 if !head.is_adjacent_with(tail) {
                     tail.update_position_by_neighbor(head);
                 }
+                motion_matrix.insert(tail);
             }
+
+            visualize::emit(|| rope_frame(&motion_matrix, &[(head, 'H'), (tail, 'T')]));
         });
 
     let ans = motion_matrix.len() as u32;
@@ -120,6 +131,7 @@ impl Rope {
 pub fn part_two(input: &str) -> Option<u32> {
     let mut rope = Rope::new(10);
     let mut motion_matrix: HashSet<MatrixPoint> = HashSet::new();
+    motion_matrix.insert(rope.tail);
 
     input
         .lines()
@@ -132,7 +144,6 @@ pub fn part_two(input: &str) -> Option<u32> {
         .for_each(|(direction, distance)| {
             let delta = DIRECTION_TO_DELTA[direction];
             for _ in 0..distance {
-                motion_matrix.insert(rope.tail);
                 rope.head.update_position_by_delta(delta);
 
                 let mut prev_knot = rope.head;
@@ -161,18 +172,20 @@ pub fn part_two(input: &str) -> Option<u32> {
                     tail: body[body.len() - 1],
                     body,
                 });
+                motion_matrix.insert(rope.tail);
             }
+
+            visualize::emit(|| {
+                let labels = "H123456789".chars();
+                rope_frame(&motion_matrix, &rope.body.iter().copied().zip(labels).collect::<Vec<_>>())
+            });
         });
 
     let ans = motion_matrix.len() as u32;
     ans.into()
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2022, 9);
 
 #[cfg(test)]
 mod tests {
@@ -180,13 +193,11 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_one(&input), Some(13));
+        advent_of_code::examples::check(YEAR, DAY, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_two(&input), Some(36));
+        advent_of_code::examples::check(YEAR, DAY, 2, part_two);
     }
 }
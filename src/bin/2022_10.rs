@@ -1,3 +1,7 @@
+use advent_of_code::helpers::ocr;
+use advent_of_code::visualize::{self, Frame};
+use advent_of_code::Answer;
+
 pub fn part_one(input: &str) -> Option<i64> {
     let mut register_x_value = 1i64;
     let mut clock_cycle_count = 1i64;
@@ -49,7 +53,15 @@ fn get_overlap_between_rx_and_cc(rx: i64, cc: i64) -> Option<i64> {
     overlap
 }
 
-pub fn part_two(input: &str) -> Option<String> {
+fn crt_rows(crt_screen: &[char]) -> Vec<String> {
+    crt_screen
+        .chunks(CHUNK_DIM)
+        .map(|chunk| chunk.iter().collect::<String>())
+        .collect::<Vec<String>>()
+}
+
+/// the CRT screen row by row, e.g. for debugging letters that could not be recognized.
+pub fn render_crt(input: &str) -> Vec<String> {
     let mut crt_screen = ['.'; CRT_DIM];
     let mut register_x_value = 1i64;
     let mut clock_cycle_count = 1i64;
@@ -86,23 +98,26 @@ pub fn part_two(input: &str) -> Option<String> {
                 }
                 _ => panic!("Unknown instruction: {}", instruction)
             }
+
+            visualize::emit(|| Frame {
+                rows: crt_rows(&crt_screen),
+            });
         });
 
-    let crt_screen_by_chunks = crt_screen
-        .chunks(CHUNK_DIM)
-        .map(|chunk| chunk.iter().collect::<String>())
-        .collect::<Vec<String>>();
+    crt_rows(&crt_screen)
+}
 
-    println!("{}", crt_screen_by_chunks.join("\n"));
+pub fn part_two(input: &str) -> Option<Answer> {
+    let screen = render_crt(input);
 
-    Some(crt_screen.iter().collect())
+    // the example draws a test pattern instead of letters, it is kept as a grid.
+    match ocr::recognize(&screen) {
+        Ok(letters) => Some(Answer::Text(letters)),
+        Err(_) => Some(Answer::grid(screen)),
+    }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2022, 10);
 
 #[cfg(test)]
 mod tests {
@@ -110,13 +125,11 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_one(&input), Some(13140));
+        advent_of_code::examples::check(YEAR, DAY, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), Some("##..##..##..##..##..##..##..##..##..##..###...###...###...###...###...###...###.####....####....####....####....####....#####.....#####.....#####.....#####.....######......######......######......###########.......#######.......#######.....".to_string()));
+        advent_of_code::examples::check(YEAR, DAY, 2, part_two);
     }
 }
@@ -0,0 +1,787 @@
+use advent_of_code::{Params, ParseError, Solution};
+use num::{BigInt, Integer, Zero};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    Old,
+    Value(u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// `new = left operator right`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Operation {
+    pub left: Operand,
+    pub operator: Operator,
+    pub right: Operand,
+}
+
+impl Operation {
+    /// the operands for the worry level `old`.
+    pub fn operands<W: Clone + From<u64>>(&self, old: &W) -> (W, W) {
+        let operand = |operand| match operand {
+            Operand::Old => old.clone(),
+            Operand::Value(value) => W::from(value),
+        };
+        (operand(self.left), operand(self.right))
+    }
+
+    /// whether the result modulo some number only depends on the operands modulo that number,
+    /// which is what allows reducing worry levels.
+    pub fn is_modular(&self) -> bool {
+        self.operator != Operator::Div
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let expression = input
+            .trim()
+            .strip_prefix("new =")
+            .ok_or_else(|| format!("expected `new = ...`, got `{}`", input.trim()))?;
+
+        let operand = |token: &str| match token {
+            "old" => Ok(Operand::Old),
+            _ => token.parse().map(Operand::Value).map_err(|_| {
+                ParseError::new(format!("expected `old` or a number, got `{}`", token))
+            }),
+        };
+
+        let (left, operator, right) = match expression.split_whitespace().collect::<Vec<_>>()[..] {
+            [left, operator, right] => (operand(left)?, operator, operand(right)?),
+            _ => return Err(format!("expected `new = a op b`, got `{}`", input.trim()).into()),
+        };
+        let operator = match operator {
+            "+" => Operator::Add,
+            "-" => Operator::Sub,
+            "*" => Operator::Mul,
+            "/" => Operator::Div,
+            _ => return Err(format!("unknown operator `{}`", operator).into()),
+        };
+        if operator == Operator::Div && right == Operand::Value(0) {
+            return Err("division by zero".into());
+        }
+
+        Ok(Operation {
+            left,
+            operator,
+            right,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DivTest {
+    pub rate: u64,
+    /// the index of the monkey, the notes refer to it by id.
+    pub true_monkey: usize,
+    pub false_monkey: usize,
+}
+
+impl DivTest {
+    pub fn apply_div_test<W: Worry>(&self, value: &W) -> usize {
+        if value.is_divisible_by(self.rate) {
+            self.true_monkey
+        } else {
+            self.false_monkey
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    /// the id from `Monkey N:`. monkeys take their turns by increasing id.
+    pub id: usize,
+    pub worry_levels: Vec<u64>,
+    pub operation: Operation,
+    pub div_test: DivTest,
+}
+
+/// the fields of a `Monkey N:` block, with the lines they were read from.
+#[derive(Default)]
+struct MonkeyNotes {
+    id: usize,
+    line: usize,
+    worry_levels: Option<Vec<u64>>,
+    operation: Option<Operation>,
+    rate: Option<u64>,
+    true_monkey: Option<(usize, usize)>,
+    false_monkey: Option<(usize, usize)>,
+}
+
+impl MonkeyNotes {
+    fn read_field(&mut self, line: &str, index: usize) -> Result<(), ParseError> {
+        let (name, value) = line
+            .split_once(':')
+            .ok_or_else(|| format!("expected `field: value`, got `{}`", line))?;
+        let (name, value) = (name.trim(), value.trim());
+
+        let target = |value: &str| -> Result<(usize, usize), ParseError> {
+            let id = value
+                .strip_prefix("throw to monkey")
+                .ok_or_else(|| format!("expected `throw to monkey N`, got `{}`", value))?;
+            Ok((id.trim().parse()?, index))
+        };
+
+        let duplicate = match name {
+            "Starting items" => {
+                let worry_levels = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|item| !item.is_empty())
+                    .map(|item| item.parse())
+                    .collect::<Result<_, _>>()?;
+                self.worry_levels.replace(worry_levels).is_some()
+            }
+            "Operation" => self.operation.replace(value.parse()?).is_some(),
+            "Test" => {
+                let rate = value
+                    .strip_prefix("divisible by")
+                    .ok_or_else(|| format!("expected `divisible by N`, got `{}`", value))?
+                    .trim()
+                    .parse()?;
+                if rate == 0 {
+                    return Err("divisible by 0".into());
+                }
+                self.rate.replace(rate).is_some()
+            }
+            "If true" => self.true_monkey.replace(target(value)?).is_some(),
+            "If false" => self.false_monkey.replace(target(value)?).is_some(),
+            _ => return Err(format!("unknown field `{}`", name).into()),
+        };
+
+        match duplicate {
+            true => Err(format!("`{}` is given twice", name).into()),
+            false => Ok(()),
+        }
+    }
+}
+
+/// the monkeys ordered by id, which is the order they take their turns in.
+fn get_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut notes: Vec<MonkeyNotes> = vec![];
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix("Monkey ") {
+            let id = header
+                .strip_suffix(':')
+                .and_then(|id| id.trim().parse().ok())
+                .ok_or_else(|| {
+                    ParseError::new(format!("expected `Monkey N:`, got `{}`", line)).at_line(index)
+                })?;
+            if notes.iter().any(|monkey| monkey.id == id) {
+                return Err(
+                    ParseError::new(format!("monkey {} is described twice", id)).at_line(index)
+                );
+            }
+            notes.push(MonkeyNotes {
+                id,
+                line: index,
+                ..MonkeyNotes::default()
+            });
+            continue;
+        }
+
+        let monkey = notes.last_mut().ok_or_else(|| {
+            ParseError::new(format!("expected `Monkey N:`, got `{}`", line)).at_line(index)
+        })?;
+        monkey.read_field(line, index).map_err(|e| {
+            ParseError::new(format!("monkey {}: {}", monkey.id, e.message)).at_line(index)
+        })?;
+    }
+
+    if notes.is_empty() {
+        return Err("no monkeys".into());
+    }
+    notes.sort_by_key(|monkey| monkey.id);
+    let indices = notes
+        .iter()
+        .enumerate()
+        .map(|(index, monkey)| (monkey.id, index))
+        .collect::<HashMap<_, _>>();
+
+    notes
+        .into_iter()
+        .map(|monkey| {
+            let MonkeyNotes {
+                id,
+                line,
+                worry_levels,
+                operation,
+                rate,
+                true_monkey,
+                false_monkey,
+            } = monkey;
+            let missing = |field: &str| {
+                ParseError::new(format!("monkey {}: missing `{}`", id, field)).at_line(line)
+            };
+            let resolve = |target: Option<(usize, usize)>, field: &str| {
+                let (target, line) = target.ok_or_else(|| missing(field))?;
+                indices.get(&target).copied().ok_or_else(|| {
+                    let message = format!("monkey {}: throws to unknown monkey {}", id, target);
+                    ParseError::new(message).at_line(line)
+                })
+            };
+
+            Ok(Monkey {
+                id,
+                worry_levels: worry_levels.ok_or_else(|| missing("Starting items"))?,
+                operation: operation.ok_or_else(|| missing("Operation"))?,
+                div_test: DivTest {
+                    rate: rate.ok_or_else(|| missing("Test"))?,
+                    true_monkey: resolve(true_monkey, "If true")?,
+                    false_monkey: resolve(false_monkey, "If false")?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// a worry level. the divisibility tests only depend on it modulo the product of all divisors,
+/// so it can be kept below that product as long as nothing divides it.
+trait Worry: Sized {
+    fn from_u64(value: u64) -> Self;
+
+    /// the worry level after `monkey` inspected the item and the relief divided it, reduced by
+    /// `modulus` if given. `None` if it does not fit or the operation divides by zero.
+    fn inspect(&self, monkey: &Monkey, relief: u64, modulus: Option<&Self>) -> Option<Self>;
+
+    fn is_divisible_by(&self, rate: u64) -> bool;
+}
+
+impl Worry for u64 {
+    fn from_u64(value: u64) -> Self {
+        value
+    }
+
+    fn inspect(&self, monkey: &Monkey, relief: u64, modulus: Option<&u64>) -> Option<u64> {
+        // both operands fit in u64, so a single operation cannot overflow u128.
+        let (left, right) = monkey.operation.operands(&(*self as u128));
+        let modulus = modulus.map(|&modulus| modulus as u128);
+
+        let mut worry_level = match (monkey.operation.operator, modulus) {
+            (Operator::Add, _) => left + right,
+            (Operator::Mul, _) => left * right,
+            // a result below zero is only known modulo the common rate.
+            (Operator::Sub, Some(modulus)) => left % modulus + modulus - right % modulus,
+            (Operator::Sub, None) => left.checked_sub(right)?,
+            (Operator::Div, _) => left.checked_div(right)?,
+        } / relief as u128;
+
+        if let Some(modulus) = modulus {
+            worry_level %= modulus;
+        }
+        u64::try_from(worry_level).ok()
+    }
+
+    fn is_divisible_by(&self, rate: u64) -> bool {
+        u64::is_multiple_of(*self, rate)
+    }
+}
+
+impl Worry for BigInt {
+    fn from_u64(value: u64) -> Self {
+        value.into()
+    }
+
+    fn inspect(&self, monkey: &Monkey, relief: u64, modulus: Option<&BigInt>) -> Option<BigInt> {
+        // worry levels below zero round down and reduce to the same remainders as in u64.
+        let (left, right) = monkey.operation.operands(self);
+        let worry_level = match monkey.operation.operator {
+            Operator::Add => left + right,
+            Operator::Sub => left - right,
+            Operator::Mul => left * right,
+            Operator::Div if right.is_zero() => return None,
+            Operator::Div => left.div_floor(&right),
+        }
+        .div_floor(&relief.into());
+
+        Some(match modulus {
+            Some(modulus) => worry_level.mod_floor(modulus),
+            None => worry_level,
+        })
+    }
+
+    fn is_divisible_by(&self, rate: u64) -> bool {
+        self.is_multiple_of(&rate.into())
+    }
+}
+
+/// an item on its way between the monkeys.
+struct Item<W> {
+    pub monkey: usize,
+    pub worry_level: W,
+}
+
+impl<W: Worry> Item<W> {
+    /// passes the item on until the round is over for it: it stays with a monkey that already
+    /// had its turn. returns `None` if the worry level does not fit in `W`.
+    pub fn play_round(
+        &mut self,
+        monkeys: &[Monkey],
+        relief: u64,
+        modulus: Option<&W>,
+        inspections: &mut [u64],
+    ) -> Option<()> {
+        loop {
+            let monkey = &monkeys[self.monkey];
+            inspections[self.monkey] += 1;
+
+            self.worry_level = self.worry_level.inspect(monkey, relief, modulus)?;
+            let next_monkey = monkey.div_test.apply_div_test(&self.worry_level);
+            let thrown_forward = monkeys[next_monkey].id > monkey.id;
+            self.monkey = next_monkey;
+
+            if !thrown_forward {
+                return Some(());
+            }
+        }
+    }
+}
+
+/// the inspections of every monkey after `rounds` rounds. items never interact, so each one is
+/// followed through all rounds on its own.
+fn simulate<W: Worry>(
+    monkeys: &[Monkey],
+    rounds: u64,
+    relief: u64,
+    modulus: Option<&W>,
+) -> Option<Vec<u64>> {
+    let mut inspections = vec![0u64; monkeys.len()];
+
+    for (owner, monkey) in monkeys.iter().enumerate() {
+        for &worry_level in &monkey.worry_levels {
+            let mut item = Item {
+                monkey: owner,
+                worry_level: W::from_u64(worry_level),
+            };
+            for _ in 0..rounds {
+                item.play_round(monkeys, relief, modulus, &mut inspections)?;
+            }
+        }
+    }
+
+    Some(inspections)
+}
+
+/// what worry levels are reduced by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Modulus {
+    /// dividing, by the relief or in an operation, does not commute with taking the remainder.
+    Unreduced,
+    /// the product of all divisors.
+    Narrow(u64),
+    /// the product of all divisors does not fit in u64.
+    Wide,
+}
+
+impl Modulus {
+    fn new(monkeys: &[Monkey], relief: u64) -> Self {
+        if relief != 1 || !monkeys.iter().all(|monkey| monkey.operation.is_modular()) {
+            return Modulus::Unreduced;
+        }
+
+        monkeys
+            .iter()
+            .try_fold(1u64, |product, monkey| {
+                product.checked_mul(monkey.div_test.rate)
+            })
+            .map_or(Modulus::Wide, Modulus::Narrow)
+    }
+}
+
+/// follows an item from round to round until it is back in a state it was in at the start of an
+/// earlier round, with the worry level reduced by `modulus`. from there on the rounds repeat, so
+/// the inspections of the remaining rounds are extrapolated.
+///
+/// until then it keeps the inspecting monkey of every inspection, at most `monkeys.len()` per
+/// round, and one entry per round for the states seen. both are bounded by the number of states,
+/// `monkeys.len() * modulus`, and by `rounds`.
+fn extrapolate_item(
+    monkeys: &[Monkey],
+    mut item: Item<u64>,
+    rounds: u64,
+    modulus: u64,
+) -> Vec<u64> {
+    // the inspecting monkeys in order, and where in that trail every round starts.
+    let (mut trail, mut round_starts) = (vec![], vec![0]);
+    let mut round_inspections = vec![0u64; monkeys.len()];
+    let mut seen = HashMap::new();
+
+    let tally = |trail: &[usize]| {
+        let mut inspections = vec![0u64; monkeys.len()];
+        for &monkey in trail {
+            inspections[monkey] += 1;
+        }
+        inspections
+    };
+
+    for round in 0..rounds {
+        let state = (item.monkey, item.worry_level);
+        if let Some(start) = seen.insert(state, round) {
+            let (start, period) = (start as usize, round - start);
+            let cycles = (rounds - round) / period;
+            let remainder = ((rounds - round) % period) as usize;
+            let cycle = &trail[round_starts[start]..];
+            let partial = &cycle[..round_starts[start + remainder] - round_starts[start]];
+
+            return tally(&trail)
+                .into_iter()
+                .zip(tally(cycle))
+                .zip(tally(partial))
+                .map(|((so_far, per_cycle), partial)| so_far + cycles * per_cycle + partial)
+                .collect();
+        }
+
+        item.play_round(monkeys, 1, Some(&modulus), &mut round_inspections)
+            .expect("worry levels below a u64 modulus fit in u64");
+        for (monkey, count) in round_inspections.iter_mut().enumerate() {
+            trail.extend(std::iter::repeat_n(monkey, *count as usize));
+            *count = 0;
+        }
+        round_starts.push(trail.len());
+    }
+
+    tally(&trail)
+}
+
+/// like `simulate` without relief, but extrapolates every item once its rounds repeat.
+fn extrapolate(monkeys: &[Monkey], rounds: u64, modulus: u64) -> Vec<u64> {
+    let mut inspections = vec![0u64; monkeys.len()];
+
+    for (owner, monkey) in monkeys.iter().enumerate() {
+        for &worry_level in &monkey.worry_levels {
+            let item = Item {
+                monkey: owner,
+                worry_level: worry_level % modulus,
+            };
+            let item_inspections = extrapolate_item(monkeys, item, rounds, modulus);
+            for (total, count) in inspections.iter_mut().zip(item_inspections) {
+                *total += count;
+            }
+        }
+    }
+
+    inspections
+}
+
+/// how the rounds are played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Strategy {
+    /// plays every single round.
+    Simulate,
+    /// detects when the rounds of an item repeat and extrapolates the rest. it only applies
+    /// without relief and with a common rate that fits in u64, otherwise every round is played.
+    Cycles,
+}
+
+/// the values of the `STRATEGY` parameter.
+const STRATEGIES: [(&str, Strategy); 2] = [
+    ("simulate", Strategy::Simulate),
+    ("cycles", Strategy::Cycles),
+];
+
+/// simulates in u64 and falls back to big integers if the common rate or an unreduced worry level
+/// does not fit. `None` if an operation divides by zero.
+fn count_inspections(
+    monkeys: &[Monkey],
+    rounds: u64,
+    relief: u64,
+    strategy: Strategy,
+) -> Option<Vec<u64>> {
+    let modulus = Modulus::new(monkeys, relief);
+    let narrow = match modulus {
+        Modulus::Narrow(modulus) if strategy == Strategy::Cycles => {
+            Some(extrapolate(monkeys, rounds, modulus))
+        }
+        Modulus::Narrow(modulus) => simulate::<u64>(monkeys, rounds, relief, Some(&modulus)),
+        Modulus::Unreduced => simulate::<u64>(monkeys, rounds, relief, None),
+        Modulus::Wide => None,
+    };
+
+    narrow.or_else(|| {
+        let wide = (modulus != Modulus::Unreduced).then(|| {
+            monkeys
+                .iter()
+                .map(|monkey| BigInt::from(monkey.div_test.rate))
+                .product::<BigInt>()
+        });
+        simulate::<BigInt>(monkeys, rounds, relief, wide.as_ref())
+    })
+}
+
+fn monkey_business(mut inspections: Vec<u64>) -> u64 {
+    inspections.sort_by(|a, b| b.cmp(a));
+    inspections.iter().take(2).product()
+}
+
+fn execute_monkey_keep_away(notes: &Notes, rounds: u64, worry_level_threshold: u64) -> Option<u64> {
+    count_inspections(
+        &notes.monkeys,
+        rounds,
+        worry_level_threshold,
+        notes.strategy,
+    )
+    .map(monkey_business)
+}
+
+struct Notes {
+    pub monkeys: Vec<Monkey>,
+    pub strategy: Strategy,
+}
+
+struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Notes;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        Ok(Notes {
+            monkeys: get_monkeys(input)?,
+            strategy: params.choice_or("STRATEGY", &STRATEGIES, Strategy::Cycles)?,
+        })
+    }
+
+    fn part_one(notes: &Self::Parsed) -> Option<u64> {
+        execute_monkey_keep_away(notes, 20, 3)
+    }
+
+    fn part_two(notes: &Self::Parsed) -> Option<u64> {
+        execute_monkey_keep_away(notes, 10000, 1)
+    }
+}
+
+advent_of_code::solution!(2022, 11, Day11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check_solution::<Day11>(YEAR, DAY, 1);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check_solution::<Day11>(YEAR, DAY, 2);
+    }
+
+    fn example_notes() -> String {
+        advent_of_code::examples::discover(YEAR, DAY)[0]
+            .input
+            .clone()
+    }
+
+    fn example_monkeys() -> Vec<Monkey> {
+        get_monkeys(&example_notes()).unwrap()
+    }
+
+    fn example_modulus() -> u64 {
+        match Modulus::new(&example_monkeys(), 1) {
+            Modulus::Narrow(modulus) => modulus,
+            modulus => panic!("unexpected {:?}", modulus),
+        }
+    }
+
+    #[test]
+    fn test_wide_fallback_agrees() {
+        let monkeys = example_monkeys();
+        let modulus = BigInt::from(example_modulus());
+
+        assert_eq!(
+            count_inspections(&monkeys, 1000, 1, Strategy::Simulate).unwrap(),
+            simulate::<BigInt>(&monkeys, 1000, 1, Some(&modulus)).unwrap()
+        );
+        assert_eq!(
+            count_inspections(&monkeys, 20, 3, Strategy::Simulate).unwrap(),
+            simulate::<BigInt>(&monkeys, 20, 3, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_common_rate_overflow() {
+        // two primes above 2^32, so the common rate does not fit in u64.
+        let mut monkeys = example_monkeys();
+        monkeys[0].div_test.rate = 4294967311;
+        monkeys[1].div_test.rate = 4294967357;
+        assert_eq!(Modulus::new(&monkeys, 1), Modulus::Wide);
+
+        assert_eq!(
+            count_inspections(&monkeys, 5, 1, Strategy::Cycles).unwrap(),
+            simulate::<BigInt>(&monkeys, 5, 1, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cycles_match_simulation() {
+        let monkeys = example_monkeys();
+
+        for rounds in [0, 1, 2, 20, 97, 500, 1000, 2500] {
+            assert_eq!(
+                count_inspections(&monkeys, rounds, 1, Strategy::Cycles),
+                count_inspections(&monkeys, rounds, 1, Strategy::Simulate),
+                "{} rounds",
+                rounds
+            );
+        }
+    }
+
+    #[test]
+    fn test_cycles_extrapolate() {
+        let monkeys = example_monkeys();
+        let modulus = example_modulus();
+
+        // every item repeats, so a billion rounds take no longer than the first period.
+        let inspections = extrapolate(&monkeys, 1_000_000_000, modulus);
+        let items = monkeys
+            .iter()
+            .map(|monkey| monkey.worry_levels.len() as u64)
+            .sum::<u64>();
+        assert!(inspections.iter().sum::<u64>() >= items * 1_000_000_000);
+        assert_eq!(
+            monkey_business(extrapolate(&monkeys, 10000, modulus)),
+            2713310158
+        );
+    }
+
+    #[test]
+    fn test_parse_layout_variations() {
+        // CRLF line endings, extra blank lines, indentation, a gap in the ids and the monkeys
+        // listed backwards.
+        let notes = example_notes().replace("onkey 3", "onkey 30");
+        let mut blocks = notes
+            .split("\n\n")
+            .map(|block| {
+                block
+                    .lines()
+                    .map(str::trim)
+                    .collect::<Vec<_>>()
+                    .join("\r\n")
+            })
+            .collect::<Vec<_>>();
+        blocks.reverse();
+        let notes = format!("\r\n{}\r\n\r\n", blocks.join("\r\n\r\n\r\n"));
+
+        let notes = Day11::parse(&notes, &Params::new()).unwrap();
+        let ids = notes.monkeys.iter().map(|monkey| monkey.id);
+        assert_eq!(ids.collect::<Vec<_>>(), [0, 1, 2, 30]);
+        assert_eq!(Day11::part_one(&notes), Some(10605));
+        assert_eq!(Day11::part_two(&notes), Some(2713310158));
+    }
+
+    #[test]
+    fn test_simulate_param() {
+        let params = Params::new().with("STRATEGY", "simulate");
+        let notes = Day11::parse(&example_notes(), &params).unwrap();
+        assert_eq!(notes.strategy, Strategy::Simulate);
+
+        // every one of the 10000 rounds is played, with the same result as the extrapolation.
+        assert_eq!(Day11::part_two(&notes), Some(2713310158));
+        let notes = Day11::parse(&example_notes(), &Params::new()).unwrap();
+        assert_eq!(notes.strategy, Strategy::Cycles);
+    }
+
+    #[test]
+    fn test_subtraction_and_division() {
+        let notes = example_notes()
+            .replace("new = old + 6", "new = 1000 - old")
+            .replace("new = old + 3", "new = old - 3");
+        let monkeys = get_monkeys(&notes).unwrap();
+        assert_eq!(monkeys[1].operation.left, Operand::Value(1000));
+        assert!(Modulus::new(&monkeys, 1) != Modulus::Unreduced);
+
+        // subtraction is reduced like the other operations.
+        for rounds in [20, 200] {
+            assert_eq!(
+                count_inspections(&monkeys, rounds, 1, Strategy::Cycles),
+                count_inspections(&monkeys, rounds, 1, Strategy::Simulate)
+            );
+        }
+        // with relief, differences below zero fall back to big integers.
+        assert!(count_inspections(&monkeys, 20, 3, Strategy::Simulate).is_some());
+
+        let monkeys = get_monkeys(&notes.replace("new = old * 19", "new = old / 2")).unwrap();
+        assert_eq!(Modulus::new(&monkeys, 1), Modulus::Unreduced);
+        assert!(count_inspections(&monkeys, 100, 1, Strategy::Cycles).is_some());
+
+        // monkey 0 throws a 0 to monkey 2.
+        let notes = notes
+            .replace("new = old * 19", "new = old - old")
+            .replace("new = old * old", "new = 100 / old");
+        let monkeys = get_monkeys(&notes).unwrap();
+        assert_eq!(count_inspections(&monkeys, 1, 1, Strategy::Simulate), None);
+        let error = get_monkeys(&notes.replace("100 / old", "old / 0")).unwrap_err();
+        assert_eq!(error.to_string(), "line 17: monkey 2: division by zero");
+    }
+
+    #[test]
+    fn test_negative_worry_levels() {
+        let notes = example_notes().replace("new = old * 19", "new = old - 10");
+        let monkeys = get_monkeys(&notes).unwrap();
+        let inspect = |worry_level: i64, relief, modulus: Option<u64>| {
+            let modulus = modulus.map(BigInt::from);
+            BigInt::from(worry_level).inspect(&monkeys[0], relief, modulus.as_ref())
+        };
+
+        // -5 / 3 rounds down, not towards zero.
+        assert_eq!(inspect(5, 3, None), Some(BigInt::from(-2)));
+        assert_eq!(inspect(-1, 3, None), Some(BigInt::from(-4)));
+        // reduced, it matches the u64 worry level.
+        assert_eq!(inspect(5, 1, Some(23)), Some(BigInt::from(18)));
+        assert_eq!(5u64.inspect(&monkeys[0], 1, Some(&23)), Some(18));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |notes: &str| get_monkeys(notes).unwrap_err().to_string();
+        let notes = example_notes();
+
+        assert_eq!(
+            error(&notes.replace("old * old", "old % old")),
+            "line 17: monkey 2: unknown operator `%`"
+        );
+        assert_eq!(
+            error(&notes.replace("new = old + 3", "new = old + three")),
+            "line 24: monkey 3: expected `old` or a number, got `three`"
+        );
+        assert_eq!(
+            error(&notes.replace("throw to monkey 1\n", "throw to monkey 7\n")),
+            "line 19: monkey 2: throws to unknown monkey 7"
+        );
+        assert_eq!(
+            error(&notes.replace("  Test: divisible by 19\n", "")),
+            "line 8: monkey 1: missing `Test`"
+        );
+        assert_eq!(
+            error(&notes.replace("Starting items: 74", "Starting tems: 74")),
+            "line 23: monkey 3: unknown field `Starting tems`"
+        );
+        assert_eq!(
+            error(&notes.replace("Monkey 3:", "Monkey 1:")),
+            "line 22: monkey 1 is described twice"
+        );
+        assert_eq!(
+            error("  Operation: new = old * 2"),
+            "line 1: expected `Monkey N:`, got `Operation: new = old * 2`"
+        );
+    }
+}
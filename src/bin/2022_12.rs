@@ -0,0 +1,76 @@
+use advent_of_code::helpers::grid::{Grid, Pos};
+use advent_of_code::helpers::search::{bfs, Search};
+use advent_of_code::visualize::{self, Frame};
+
+fn get_hill_matrix(input: &str) -> (Pos, Pos, Grid<char>) {
+    let mut hill_matrix = input.parse::<Grid<char>>().unwrap();
+    let start = hill_matrix.find(&'S').unwrap_or((0, 0));
+    let end = hill_matrix
+        .find(&'E')
+        .unwrap_or((hill_matrix.height() - 1, hill_matrix.width() - 1));
+
+    hill_matrix[start] = 'a';
+    hill_matrix[end] = 'z';
+    (start, end, hill_matrix)
+}
+
+/// the neighbours of `pos` that can be climbed to: at most one higher, any lower.
+fn climbable(hill_matrix: &Grid<char>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    let max_height = hill_matrix[pos] as u8 + 1;
+    hill_matrix
+        .neighbours4(pos)
+        .filter(move |&next| hill_matrix[next] as u8 <= max_height)
+}
+
+/// the path to the goal drawn like in the puzzle description: every step as an arrow.
+fn path_frame(hill_matrix: &Grid<char>, search: &Search<Pos, usize>) -> Frame {
+    let mut map = hill_matrix.map(|_| '.');
+    let path = search.goal_path().unwrap_or_default();
+
+    for step in path.windows(2) {
+        let ((row, column), next) = (step[0], step[1]);
+        map[step[0]] = match next {
+            _ if next.0 > row => 'v',
+            _ if next.0 < row => '^',
+            _ if next.1 > column => '>',
+            _ => '<',
+        };
+    }
+    if let Some(&end) = path.last() {
+        map[end] = 'E';
+    }
+
+    Frame::from_grid(&map, |&cell| cell)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (start, end, hill_matrix) = get_hill_matrix(input);
+    let search = bfs([start], |&pos| climbable(&hill_matrix, pos), |&pos| pos == end);
+    visualize::emit(|| path_frame(&hill_matrix, &search));
+    search.goal_distance().map(|steps| steps as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let (_, end, hill_matrix) = get_hill_matrix(input);
+    let starts = hill_matrix.find_all(&'a');
+    let search = bfs(starts, |&pos| climbable(&hill_matrix, pos), |&pos| pos == end);
+    visualize::emit(|| path_frame(&hill_matrix, &search));
+    search.goal_distance().map(|steps| steps as u32)
+}
+
+advent_of_code::solution!(2022, 12);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check(YEAR, DAY, 1, part_one);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check(YEAR, DAY, 2, part_two);
+    }
+}
@@ -0,0 +1,367 @@
+use advent_of_code::{ParseError, Params, Solution};
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::iter::Peekable;
+use std::str::{CharIndices, FromStr};
+
+const DIVIDERS: [&str; 2] = ["[[2]]", "[[6]]"];
+
+/// packets compare by the puzzle rules, so `[[1]]` equals `1`. compare their `to_string()` to
+/// tell such packets apart.
+#[derive(Debug, Clone)]
+enum Packet {
+    Int(u32),
+    List(Vec<Packet>),
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl Ord for Packet {
+    /// packets are in the right order if the left one is less than the right one.
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Int(a), Packet::Int(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => a.cmp(b),
+            // an integer compares like a list holding just that integer.
+            (Packet::Int(_), Packet::List(b)) => std::slice::from_ref(self).cmp(b.as_slice()),
+            (Packet::List(a), Packet::Int(_)) => a.as_slice().cmp(std::slice::from_ref(other)),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Int(value) => write!(f, "{}", value),
+            Packet::List(children) => {
+                write!(f, "[")?;
+                for (index, child) in children.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", child)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut parser = PacketParser {
+            chars: input.char_indices().peekable(),
+            len: input.len(),
+        };
+
+        let packet = parser.list()?;
+        match parser.chars.next() {
+            None => Ok(packet),
+            Some((position, c)) => Err(PacketParser::unexpected(position, Some(c), "end of packet")),
+        }
+    }
+}
+
+/// a recursive descent parser over `list := '[' (packet (',' packet)*)? ']'` and
+/// `packet := list | integer`. errors name the column they occur in.
+struct PacketParser<'a> {
+    chars: Peekable<CharIndices<'a>>,
+    len: usize,
+}
+
+impl PacketParser<'_> {
+    fn packet(&mut self) -> Result<Packet, ParseError> {
+        match self.chars.peek() {
+            Some((_, '[')) => self.list(),
+            Some((_, c)) if c.is_ascii_digit() => self.int(),
+            _ => Err(self.expected("`[` or a digit")),
+        }
+    }
+
+    fn list(&mut self) -> Result<Packet, ParseError> {
+        match self.chars.next() {
+            Some((_, '[')) => {}
+            next => return Err(self.unexpected_at(next, "`[`")),
+        }
+
+        let mut children = vec![];
+        if let Some((_, ']')) = self.chars.peek() {
+            self.chars.next();
+            return Ok(Packet::List(children));
+        }
+
+        loop {
+            children.push(self.packet()?);
+            match self.chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, ']')) => return Ok(Packet::List(children)),
+                next => return Err(self.unexpected_at(next, "`,` or `]`")),
+            }
+        }
+    }
+
+    fn int(&mut self) -> Result<Packet, ParseError> {
+        let (start, mut digits) = (self.position(), String::new());
+        while let Some(&(_, c)) = self.chars.peek().filter(|(_, c)| c.is_ascii_digit()) {
+            digits.push(c);
+            self.chars.next();
+        }
+
+        digits
+            .parse()
+            .map(Packet::Int)
+            .map_err(|e| ParseError::new(format!("column {}: `{}`: {}", start + 1, digits, e)))
+    }
+
+    fn position(&mut self) -> usize {
+        self.chars.peek().map_or(self.len, |&(position, _)| position)
+    }
+
+    fn expected(&mut self, expected: &str) -> ParseError {
+        let next = self.chars.peek().copied();
+        self.unexpected_at(next, expected)
+    }
+
+    fn unexpected_at(&self, next: Option<(usize, char)>, expected: &str) -> ParseError {
+        match next {
+            Some((position, c)) => Self::unexpected(position, Some(c), expected),
+            None => Self::unexpected(self.len, None, expected),
+        }
+    }
+
+    fn unexpected(position: usize, found: Option<char>, expected: &str) -> ParseError {
+        let found = match found {
+            Some(c) => format!("`{}`", c),
+            None => "the end of the line".to_string(),
+        };
+        ParseError::new(format!("column {}: expected {}, got {}", position + 1, expected, found))
+    }
+}
+
+struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<(Packet, Packet)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str, _params: &Params) -> Result<Self::Parsed, ParseError> {
+        let packets = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| line.trim().parse::<Packet>().map_err(|e| e.at_line(index)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if packets.len() % 2 != 0 {
+            return Err("expected the packets in pairs".into());
+        }
+
+        Ok(packets
+            .chunks(2)
+            .map(|pair| (pair[0].clone(), pair[1].clone()))
+            .collect())
+    }
+
+    fn part_one(pairs: &Self::Parsed) -> Option<u32> {
+        pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| left < right)
+            .map(|(index, _)| (index + 1) as u32)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_two(pairs: &Self::Parsed) -> Option<u32> {
+        let dividers = DIVIDERS
+            .iter()
+            .map(|divider| divider.parse::<Packet>())
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+
+        let mut signals = pairs
+            .iter()
+            .flat_map(|(left, right)| [left.clone(), right.clone()])
+            .chain(dividers.iter().cloned())
+            .collect::<Vec<_>>();
+        signals.sort();
+
+        dividers
+            .iter()
+            .map(|divider| (signals.partition_point(|signal| signal < divider) + 1) as u32)
+            .product::<u32>()
+            .into()
+    }
+}
+
+advent_of_code::solution!(2022, 13, Day13);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check_solution::<Day13>(YEAR, DAY, 1);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check_solution::<Day13>(YEAR, DAY, 2);
+    }
+
+    #[test]
+    fn test_packet_order() {
+        let packet = |input: &str| input.parse::<Packet>().unwrap();
+
+        assert!(packet("[]") < packet("[[]]"));
+        assert!(packet("[[]]") < packet("[0]"));
+        assert_eq!(packet("[[1]]"), Packet::Int(1));
+        assert_ne!(packet("[[1]]").to_string(), packet("[1]").to_string());
+        assert!(packet("[[4,4],4,4]") < packet("[[4,4],4,4,4]"));
+        assert_eq!(packet("[10,[],[[3]]]").to_string(), "[10,[],[[3]]]");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| input.parse::<Packet>().unwrap_err().to_string();
+
+        assert_eq!(error("[1,,2]"), "column 4: expected `[` or a digit, got `,`");
+        assert_eq!(error("[1,[2]"), "column 7: expected `,` or `]`, got the end of the line");
+        assert_eq!(error("1"), "column 1: expected `[`, got `1`");
+        assert_eq!(error("[1]]"), "column 4: expected end of packet, got `]`");
+        assert_eq!(error("[99999999999]").split(':').next(), Some("column 2"));
+
+        let error = Day13::parse("[1]\n[2]\n\n[3]\n[x]", &Params::new()).unwrap_err();
+        assert_eq!(error.to_string(), "line 5: column 2: expected `[` or a digit, got `x`");
+    }
+
+    /// a xorshift generator, so the properties run offline and reproducibly.
+    struct Rng(u64);
+
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, bound: u64) -> u64 {
+            self.next() % bound
+        }
+
+        /// a random list. small integers and short lists make equal prefixes and ties likely.
+        fn packet(&mut self, depth: u32) -> Packet {
+            let len = self.below(5);
+            Packet::List((0..len).map(|_| self.element(depth)).collect())
+        }
+
+        fn element(&mut self, depth: u32) -> Packet {
+            match depth > 0 && self.below(3) == 0 {
+                true => self.packet(depth - 1),
+                false => Packet::Int(self.below(6) as u32),
+            }
+        }
+
+        /// `packet` with one of its integers replaced by a list holding just that integer.
+        fn wrap_integer(&mut self, packet: &Packet) -> Packet {
+            let integers = count_integers(packet);
+            match integers {
+                0 => packet.clone(),
+                _ => wrap_nth_integer(packet, &mut self.below(integers)),
+            }
+        }
+    }
+
+    fn count_integers(packet: &Packet) -> u64 {
+        match packet {
+            Packet::Int(_) => 1,
+            Packet::List(children) => children.iter().map(count_integers).sum(),
+        }
+    }
+
+    fn wrap_nth_integer(packet: &Packet, n: &mut u64) -> Packet {
+        match packet {
+            Packet::Int(_) => {
+                let wrap = *n == 0;
+                *n = n.wrapping_sub(1);
+                match wrap {
+                    true => Packet::List(vec![packet.clone()]),
+                    false => packet.clone(),
+                }
+            }
+            Packet::List(children) => {
+                Packet::List(children.iter().map(|child| wrap_nth_integer(child, n)).collect())
+            }
+        }
+    }
+
+    fn random_packets(seed: u64, count: usize) -> (Rng, Vec<Packet>) {
+        let mut rng = Rng(seed);
+        let packets = (0..count).map(|_| rng.packet(3)).collect();
+        (rng, packets)
+    }
+
+    #[test]
+    fn test_order_is_total() {
+        let (_, packets) = random_packets(0x2022_1213, 40);
+
+        for a in &packets {
+            assert_eq!(a.cmp(a), Ordering::Equal, "{}", a);
+            for b in &packets {
+                assert_eq!(a.cmp(b), b.cmp(a).reverse(), "{} and {}", a, b);
+                for c in &packets {
+                    if a <= b && b <= c {
+                        assert!(a <= c, "{} <= {} <= {}", a, b, c);
+                    }
+                }
+            }
+        }
+
+        // a total order sorts into a sequence where every neighbour pair is ordered.
+        let mut sorted = packets.clone();
+        sorted.sort();
+        assert!(sorted.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        let (_, packets) = random_packets(0xdecade, 500);
+
+        for packet in packets {
+            let parsed = packet.to_string().parse::<Packet>().unwrap();
+            assert_eq!(parsed.to_string(), packet.to_string());
+            assert_eq!(parsed, packet);
+        }
+    }
+
+    #[test]
+    fn test_wrapping_integers_keeps_order() {
+        let (mut rng, packets) = random_packets(0x5eed, 60);
+
+        for a in &packets {
+            let wrapped = rng.wrap_integer(a);
+            assert_eq!(wrapped.cmp(a), Ordering::Equal, "{} and {}", wrapped, a);
+            for b in &packets {
+                assert_eq!(wrapped.cmp(b), a.cmp(b), "{} and {} against {}", wrapped, a, b);
+                assert_eq!(b.cmp(&wrapped), b.cmp(a), "{} against {} and {}", b, wrapped, a);
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use advent_of_code::helpers::grid::{Grid, Pos};
+use advent_of_code::visualize::{self, Frame};
+use advent_of_code::{ParseError, Params, Solution};
+
+// default of the `SOURCE_X` parameter.
+const SOURCE_X: i64 = 500;
+
+const AIR: char = '.';
+const ROCK: char = '#';
+const SAND: char = 'o';
+const SOURCE: char = '+';
+/// grains between two frames of `--visualize`.
+const FRAME_GRAINS: u32 = 20;
+
+type Point = (i64, i64);
+
+/// how a grain finds its way down after the previous one came to rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fill {
+    /// every grain falls all the way from the source.
+    FromSource,
+    /// every grain continues from the position above the resting place of the previous one,
+    /// because both fell the same way until there.
+    MemoisedPath,
+}
+
+/// the scanned part of the cave. it is just wide enough for the pile that part two heaps up on
+/// the floor, which is not stored: every position below the last row is rock.
+#[derive(Clone)]
+struct Cave {
+    pub grid: Grid<char>,
+    pub source: Pos,
+    /// row of the lowest rock, sand falling below it is lost without a floor.
+    pub lowest_rock: usize,
+}
+
+impl Cave {
+    /// pours sand until a grain falls into the abyss or, with a floor, the source is blocked.
+    /// returns the number of grains at rest.
+    pub fn pour(&mut self, floor: bool, fill: Fill) -> u32 {
+        let mut path = vec![self.source];
+        let mut grains = 0u32;
+
+        while let Some(&(row, column)) = path.last() {
+            if !floor && row > self.lowest_rock {
+                break;
+            }
+
+            let next = match row + 1 == self.grid.height() {
+                true => None,
+                false => [column, column - 1, column + 1]
+                    .into_iter()
+                    .map(|next_column| (row + 1, next_column))
+                    .find(|&next| self.grid[next] == AIR),
+            };
+
+            match next {
+                Some(next) => path.push(next),
+                None => {
+                    self.grid[(row, column)] = SAND;
+                    grains += 1;
+                    if grains.is_multiple_of(FRAME_GRAINS) {
+                        visualize::emit(|| self.frame());
+                    }
+                    path.pop();
+                    if fill == Fill::FromSource {
+                        path.truncate(1);
+                    }
+                }
+            }
+        }
+
+        visualize::emit(|| self.frame());
+        grains
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::from_grid(&self.grid, |&cell| cell).cropped()
+    }
+}
+
+fn get_rock_paths(input: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.split(" -> ")
+                .map(|token| {
+                    let (x, y) = token
+                        .split_once(',')
+                        .ok_or_else(|| format!("expected `x,y`, got `{}`", token))?;
+                    Ok((x.trim().parse()?, y.trim().parse()?))
+                })
+                .collect::<Result<Vec<_>, ParseError>>()
+                .map_err(|e| e.at_line(index))
+        })
+        .collect()
+}
+
+fn get_cave(input: &str, source_x: i64) -> Result<Cave, ParseError> {
+    let rock_paths = get_rock_paths(input)?;
+    let rocks = rock_paths.iter().flatten();
+
+    let lowest_rock = rocks.clone().map(|&(_, y)| y).max().ok_or("no rock paths")?;
+    if rocks.clone().any(|&(_, y)| y <= 0) {
+        return Err("rocks have to be below the source".into());
+    }
+
+    // the pile on the floor is a triangle below the source.
+    let floor = lowest_rock + 2;
+    let left = rocks.clone().map(|&(x, _)| x).min().unwrap_or(source_x).min(source_x - floor);
+    let right = rocks.map(|&(x, _)| x).max().unwrap_or(source_x).max(source_x + floor);
+    let position = |(x, y): Point| (y as usize, (x - left) as usize);
+
+    let mut grid = Grid::new((right - left + 1) as usize, floor as usize, AIR);
+
+    for (index, rock_path) in rock_paths.iter().enumerate() {
+        for adj_pos in rock_path.windows(2) {
+            let ((x1, y1), (x2, y2)) = (adj_pos[0], adj_pos[1]);
+
+            if x1 == x2 {
+                for y in y1.min(y2)..=y1.max(y2) {
+                    grid[position((x1, y))] = ROCK;
+                }
+            } else if y1 == y2 {
+                for x in x1.min(x2)..=x1.max(x2) {
+                    grid[position((x, y1))] = ROCK;
+                }
+            } else {
+                let message = format!("diagonal rock from {},{} to {},{}", x1, y1, x2, y2);
+                return Err(ParseError::new(message).at_line(index));
+            }
+        }
+    }
+
+    let source = position((source_x, 0));
+    grid[source] = SOURCE;
+
+    Ok(Cave {
+        grid,
+        source,
+        lowest_rock: lowest_rock as usize,
+    })
+}
+
+struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Cave;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        get_cave(input, params.get_or("SOURCE_X", SOURCE_X)?)
+    }
+
+    fn part_one(cave: &Self::Parsed) -> Option<u32> {
+        Some(cave.clone().pour(false, Fill::MemoisedPath))
+    }
+
+    fn part_two(cave: &Self::Parsed) -> Option<u32> {
+        Some(cave.clone().pour(true, Fill::MemoisedPath))
+    }
+}
+
+advent_of_code::solution!(2022, 14, Day14);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check_solution::<Day14>(YEAR, DAY, 1);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check_solution::<Day14>(YEAR, DAY, 2);
+    }
+
+    #[test]
+    fn test_fill_strategies_agree() {
+        for example in advent_of_code::examples::discover(YEAR, DAY) {
+            let cave = Day14::parse(&example.input, &example.params).unwrap();
+
+            for floor in [false, true] {
+                assert_eq!(
+                    cave.clone().pour(floor, Fill::FromSource),
+                    cave.clone().pour(floor, Fill::MemoisedPath)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_cave_grows_to_fit() {
+        // the example moved next to x = 0: the pile of part two reaches negative columns.
+        let input = "4,4 -> 4,6 -> 2,6\n9,4 -> 8,4 -> 8,9 -> 0,9";
+        let cave = Day14::parse(input, &Params::new().with("SOURCE_X", 6)).unwrap();
+
+        assert_eq!(Day14::part_one(&cave), Some(24));
+        assert_eq!(Day14::part_two(&cave), Some(93));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day14::parse("498,4 -> 498,6\n498,4 -> 500,6", &Params::new()).err().unwrap();
+        assert_eq!(error.to_string(), "line 2: diagonal rock from 498,4 to 500,6");
+
+        let error = Day14::parse("498,4 -> 498", &Params::new()).err().unwrap();
+        assert_eq!(error.to_string(), "line 1: expected `x,y`, got `498`");
+    }
+}
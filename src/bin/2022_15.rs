@@ -0,0 +1,249 @@
+use advent_of_code::helpers::intervals::IntervalSet;
+use advent_of_code::{Params, ParseError, Solution};
+use std::ops::RangeInclusive;
+
+// defaults of the `Y_QUERY`, `Y_START` and `Y_END` parameters, `STRATEGY` defaults to the
+// boundary lines.
+const Y_QUERY: i64 = 2000000;
+const Y_START: i64 = 0;
+const Y_END: i64 = 4000000;
+const TUNING_MULTIPLIER: i64 = 4000000;
+
+type Point = (i64, i64);
+
+struct Sensor {
+    pub origin: Point,
+    pub closest_beacon: Point,
+    pub distance: i64,
+}
+
+impl Sensor {
+    pub fn new(origin: Point, closest_beacon: Point) -> Self {
+        let distance = Self::distance(origin, closest_beacon);
+
+        Self {
+            origin,
+            closest_beacon,
+            distance,
+        }
+    }
+
+    fn distance(origin: Point, closest_beacon: Point) -> i64 {
+        let (x1, y1) = origin;
+        let (x2, y2) = closest_beacon;
+
+        (x1.abs_diff(x2) + y1.abs_diff(y2)) as i64
+    }
+
+    /// the x coordinates of `relative_y` within the distance of the sensor.
+    pub fn get_influence_interval(&self, relative_y: i64) -> Option<RangeInclusive<i64>> {
+        let (x, y) = self.origin;
+
+        if relative_y.abs_diff(y) > self.distance as u64 {
+            return None;
+        }
+
+        let remaining_distance = self.distance - (relative_y.abs_diff(y) as i64);
+        let x_min = x - remaining_distance;
+        let x_max = x + remaining_distance;
+
+        Some(x_min..=x_max)
+    }
+
+    pub fn covers(&self, point: Point) -> bool {
+        Self::distance(self.origin, point) <= self.distance
+    }
+}
+
+fn get_sensors(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| get_sensor(line).map_err(|e| e.at_line(index)))
+        .collect()
+}
+
+fn get_sensor(line: &str) -> Result<Sensor, ParseError> {
+    let (x1, y1, x2, y2) = match line.split(' ').collect::<Vec<_>>()[..] {
+        [_, _, x1, y1, _, _, _, _, x2, y2] => (x1, y1, x2, y2),
+        _ => return Err(format!("expected a sensor report, got `{}`", line).into()),
+    };
+    let coordinate = |part: &str| {
+        part.trim_start_matches(['x', 'y', '='])
+            .trim_end_matches([',', ':'])
+            .parse::<i64>()
+    };
+
+    let origin = (coordinate(x1)?, coordinate(y1)?);
+    let closest_beacon = (coordinate(x2)?, coordinate(y2)?);
+
+    Ok(Sensor::new(origin, closest_beacon))
+}
+
+fn get_influence_intervals(sensors: &[Sensor], relative_y: i64) -> IntervalSet<i64> {
+    sensors
+        .iter()
+        .filter_map(|sensor| sensor.get_influence_interval(relative_y))
+        .collect()
+}
+
+/// how part two looks for the only uncovered position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Strategy {
+    /// merges the covered intervals of every row until one of them has a gap.
+    RowScan,
+    /// intersects the lines just outside the sensors' ranges, scanning rows if that fails.
+    BoundaryLines,
+}
+
+/// the values of the `STRATEGY` parameter.
+const STRATEGIES: [(&str, Strategy); 2] = [
+    ("row-scan", Strategy::RowScan),
+    ("boundary-lines", Strategy::BoundaryLines),
+];
+
+/// looks for the uncovered position with both coordinates in `area`.
+fn find_distress_beacon(
+    sensors: &[Sensor],
+    area: &RangeInclusive<i64>,
+    strategy: Strategy,
+) -> Option<Point> {
+    match strategy {
+        Strategy::RowScan => scan_rows(sensors, area),
+        Strategy::BoundaryLines => {
+            intersect_boundaries(sensors, area).or_else(|| scan_rows(sensors, area))
+        }
+    }
+}
+
+fn scan_rows(sensors: &[Sensor], area: &RangeInclusive<i64>) -> Option<Point> {
+    area.clone().find_map(|y| {
+        get_influence_intervals(sensors, y)
+            .gaps(area.clone())
+            .first()
+            .map(|gap| (*gap.start(), y))
+    })
+}
+
+/// a single uncovered position is enclosed by the diamonds around it, so unless it lies on the
+/// edge of the search area it sits where two of their boundaries at `distance + 1` cross.
+fn intersect_boundaries(sensors: &[Sensor], area: &RangeInclusive<i64>) -> Option<Point> {
+    // lines `x - y = c` and `x + y = c`.
+    let (mut ascending, mut descending) = (vec![], vec![]);
+    for sensor in sensors {
+        let (x, y) = sensor.origin;
+        let reach = sensor.distance + 1;
+        ascending.extend([x - y - reach, x - y + reach]);
+        descending.extend([x + y - reach, x + y + reach]);
+    }
+    ascending.sort();
+    ascending.dedup();
+    descending.sort();
+    descending.dedup();
+
+    descending
+        .iter()
+        .flat_map(|&sum| ascending.iter().map(move |&difference| (sum, difference)))
+        // lines of different parity cross between two positions.
+        .filter(|(sum, difference)| (sum - difference) % 2 == 0)
+        .map(|(sum, difference)| ((sum + difference) / 2, (sum - difference) / 2))
+        .filter(|&(x, y)| area.contains(&x) && area.contains(&y))
+        .filter(|&point| sensors.iter().all(|sensor| !sensor.covers(point)))
+        .min()
+}
+
+struct Report {
+    pub sensors: Vec<Sensor>,
+    pub y_query: i64,
+    pub search_area: RangeInclusive<i64>,
+    pub strategy: Strategy,
+}
+
+struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Report;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str, params: &Params) -> Result<Self::Parsed, ParseError> {
+        Ok(Report {
+            sensors: get_sensors(input)?,
+            y_query: params.get_or("Y_QUERY", Y_QUERY)?,
+            search_area: params.get_or("Y_START", Y_START)?..=params.get_or("Y_END", Y_END)?,
+            strategy: params.choice_or("STRATEGY", &STRATEGIES, Strategy::BoundaryLines)?,
+        })
+    }
+
+    fn part_one(report: &Self::Parsed) -> Option<i64> {
+        let intervals = get_influence_intervals(&report.sensors, report.y_query);
+
+        let mut beacons = report
+            .sensors
+            .iter()
+            .map(|sensor| sensor.closest_beacon)
+            .filter(|&(x, y)| y == report.y_query && intervals.contains(x))
+            .collect::<Vec<_>>();
+
+        beacons.sort();
+        beacons.dedup();
+
+        Some(intervals.len() as i64 - beacons.len() as i64)
+    }
+
+    fn part_two(report: &Self::Parsed) -> Option<i64> {
+        find_distress_beacon(&report.sensors, &report.search_area, report.strategy)
+            .map(|(x, y)| x * TUNING_MULTIPLIER + y)
+    }
+}
+
+advent_of_code::solution!(2022, 15, Day15);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check_solution::<Day15>(YEAR, DAY, 1);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check_solution::<Day15>(YEAR, DAY, 2);
+    }
+
+    #[test]
+    fn test_strategies_agree() {
+        for example in advent_of_code::examples::discover(YEAR, DAY) {
+            let report = Day15::parse(&example.input, &example.params).unwrap();
+            let (sensors, area) = (&report.sensors, &report.search_area);
+            let row_scan = find_distress_beacon(sensors, area, Strategy::RowScan);
+            assert_eq!(intersect_boundaries(sensors, area), row_scan);
+
+            for (name, strategy) in STRATEGIES {
+                let params = example.params.clone().with("STRATEGY", name);
+                let report = Day15::parse(&example.input, &params).unwrap();
+                let beacon = find_distress_beacon(sensors, area, report.strategy);
+                assert_eq!(report.strategy, strategy);
+                assert_eq!(beacon, row_scan, "{} of {}", name, example.label());
+            }
+
+            if let Some(frequency) = example.expected(2) {
+                let frequency = frequency.parse::<i64>().unwrap();
+                let expected = (frequency / TUNING_MULTIPLIER, frequency % TUNING_MULTIPLIER);
+                assert_eq!(row_scan, Some(expected), "{}", example.label());
+            }
+        }
+    }
+
+    #[test]
+    fn test_boundary_lines_fallback() {
+        // without sensors there are no boundaries, every position is uncovered.
+        assert_eq!(intersect_boundaries(&[], &(0..=20)), None);
+        assert_eq!(
+            find_distress_beacon(&[], &(0..=20), Strategy::BoundaryLines),
+            Some((0, 0))
+        );
+    }
+}
@@ -2,104 +2,311 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
-use std::{fs, process};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// overrides the session cookie stored in `~/.adventofcode.session`.
+const SESSION_ENV_VAR: &str = "AOC_SESSION";
+const SESSION_FILE: &str = ".adventofcode.session";
+/// overrides the website, e.g. to point it at a local stub server in tests.
+const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/WarriorsSami/Advent-Of-Code-2k22)"
+);
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: i16,
+    force: bool,
+    base_url: String,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
+        force: args.contains("--force"),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        base_url: args
+            .opt_value_from_str("--base-url")?
+            .or_else(|| env::var(BASE_URL_ENV_VAR).ok())
+            .unwrap_or_else(|| BASE_URL.into()),
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
     })
 }
 
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+}
+
+fn read_session() -> Result<String, String> {
+    // an empty variable counts as unset.
+    if let Some(session) = env::var(SESSION_ENV_VAR)
+        .ok()
+        .filter(|session| !session.trim().is_empty())
     {
-        fs::remove_file(path);
+        return Ok(session.trim().to_string());
+    }
+
+    let path = home_dir()
+        .ok_or("could not determine home directory")?
+        .join(SESSION_FILE);
+
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        _ => Err(format!(
+            "no session cookie found. Set {} or paste it into \"{}\".",
+            SESSION_ENV_VAR,
+            path.display()
+        )),
     }
 }
 
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
+fn fetch_input(base_url: &str, year: i16, day: u8, session: &str) -> Result<String, String> {
+    let url = format!(
+        "{}/{}/day/{}/input",
+        base_url.trim_end_matches('/'),
+        year,
+        day
+    );
+    println!("Downloading input from {}", url);
+
+    match ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .call()
+    {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| format!("could not read response: {}", e)),
+        Err(ureq::Error::Status(404, _)) => Err(format!(
+            "input for day {} of {} is not available (yet).",
+            day, year
+        )),
+        Err(ureq::Error::Status(400, _)) => {
+            Err("server rejected the session cookie, try refreshing it.".into())
+        }
+        Err(ureq::Error::Status(status, _)) => {
+            Err(format!("request failed with status {}.", status))
+        }
+        Err(e) => Err(format!("request failed: {}", e)),
+    }
 }
 
-fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
+/// writes to a temporary sibling file first, so concurrent downloads never see partial inputs.
+fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    let file_name = path.file_name().unwrap().to_string_lossy();
+    let tmp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, process::id()));
+
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp_path);
+    })
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Outcome {
+    Downloaded,
+    Skipped,
+}
 
+/// whether `input_path` holds an input already. the empty placeholder created by
+/// `cargo scaffold` does not count.
+fn is_downloaded(input_path: &Path) -> bool {
+    fs::read_to_string(input_path).is_ok_and(|contents| !contents.trim().is_empty())
+}
+
+/// `session` is only resolved if the input has to be downloaded.
+fn download(
+    args: &Args,
+    session: impl FnOnce() -> Result<String, String>,
+    input_path: &Path,
+) -> Result<Outcome, String> {
+    if !args.force && is_downloaded(input_path) {
+        return Ok(Outcome::Skipped);
+    }
+
+    let input = fetch_input(&args.base_url, args.year, args.day, &session()?)?;
+    if let Some(dir) = input_path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("could not create input folder: {}", e))?;
+    }
+    write_atomically(input_path, &input)
+        .map_err(|e| format!("could not write input file: {}", e))?;
+    Ok(Outcome::Downloaded)
+}
+
+fn main() {
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
+    let input_path = match advent_of_code::input::puzzle_path("inputs", args.year, args.day) {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    match download(&args, read_session, &input_path) {
+        Ok(Outcome::Downloaded) => {
+            println!("---");
+            println!(
+                "🎄 Successfully wrote input to \"{}\".",
+                input_path.display()
+            );
+        }
+        Ok(Outcome::Skipped) => {
+            println!(
+                "🎄 Input \"{}\" already exists. Pass --force to download it again.",
+                input_path.display()
+            );
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// serves a single request with `status` and `body`, returning the request head it received.
+    fn stub_server(status: &str, body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let status = status.to_string();
 
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                request.push_str(&line);
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+
+        (base_url, handle)
     }
 
-    let mut cmd_args = vec![];
+    fn temp_input_path(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_download_{}_{}", name, process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir.join("07.txt")
+    }
 
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    fn session() -> Result<String, String> {
+        Ok("secret".to_string())
     }
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
-
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
-
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+    fn args(base_url: String, force: bool) -> Args {
+        Args {
+            day: 7,
+            year: 2022,
+            force,
+            base_url,
         }
     }
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
-        }
-        Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
-        }
+    #[test]
+    fn test_download() {
+        let (base_url, server) = stub_server("200 OK", "$ cd /\n$ ls\n");
+        let input_path = temp_input_path("fresh");
+        let _ = fs::remove_file(&input_path);
+
+        let outcome = download(&args(base_url, false), session, &input_path);
+        let request = server.join().unwrap().to_lowercase();
+
+        assert_eq!(outcome, Ok(Outcome::Downloaded));
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "$ cd /\n$ ls\n");
+        assert!(request.starts_with("get /2022/day/7/input "));
+        assert!(request.contains("cookie: session=secret"));
+        assert!(request.contains("user-agent: advent_of_code/"));
+    }
+
+    #[test]
+    fn test_download_skips_existing_input() {
+        let input_path = temp_input_path("existing");
+        fs::write(&input_path, "old").unwrap();
+
+        // nothing listens on this address: any request would fail, and so does the session.
+        let no_session = || Err("no session cookie found".to_string());
+        let outcome = download(
+            &args("http://127.0.0.1:9".into(), false),
+            no_session,
+            &input_path,
+        );
+
+        assert_eq!(outcome, Ok(Outcome::Skipped));
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "old");
+    }
+
+    #[test]
+    fn test_download_replaces_placeholder() {
+        let (base_url, server) = stub_server("200 OK", "new");
+        let input_path = temp_input_path("placeholder");
+        fs::write(&input_path, "\n").unwrap();
+
+        let outcome = download(&args(base_url, false), session, &input_path);
+        server.join().unwrap();
+
+        assert_eq!(outcome, Ok(Outcome::Downloaded));
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "new");
+    }
+
+    #[test]
+    fn test_download_force() {
+        let (base_url, server) = stub_server("200 OK", "new");
+        let input_path = temp_input_path("force");
+        fs::write(&input_path, "old").unwrap();
+
+        let outcome = download(&args(base_url, true), session, &input_path);
+        server.join().unwrap();
+
+        assert_eq!(outcome, Ok(Outcome::Downloaded));
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "new");
+    }
+
+    #[test]
+    fn test_download_not_available() {
+        let (base_url, server) = stub_server("404 Not Found", "");
+        let input_path = temp_input_path("missing");
+
+        let outcome = download(&args(base_url, true), session, &input_path);
+        server.join().unwrap();
+
+        assert!(outcome.unwrap_err().contains("not available"));
     }
 }
@@ -3,12 +3,54 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
+const MODULE_TEMPLATE: &str = r###"use advent_of_code::{ParseError, Params, Solution};
+
+struct Day{DAY_PADDED};
+
+impl Solution for Day{DAY_PADDED} {
+    type Parsed = Vec<String>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str, _params: &Params) -> Result<Self::Parsed, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_one(parsed: &Self::Parsed) -> Option<u32> {
+        None
+    }
+
+    fn part_two(parsed: &Self::Parsed) -> Option<u32> {
+        None
+    }
+}
+
+advent_of_code::solution!({YEAR}, {DAY}, Day{DAY_PADDED});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check_solution::<Day{DAY_PADDED}>(YEAR, DAY, 1);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check_solution::<Day{DAY_PADDED}>(YEAR, DAY, 2);
+    }
+}
+"###;
+
+/// free functions that parse the input themselves, generated with `--legacy`.
+const LEGACY_MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
     None
 }
 
@@ -16,11 +58,7 @@ pub fn part_two(input: &str) -> Option<u32> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!({YEAR}, {DAY});
 
 #[cfg(test)]
 mod tests {
@@ -28,45 +66,70 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+        advent_of_code::examples::check(YEAR, DAY, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+        advent_of_code::examples::check(YEAR, DAY, 2, part_two);
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+struct Args {
+    day: u8,
+    year: i16,
+    legacy: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        legacy: args.contains("--legacy"),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        day: args.free_from_str()?,
+    })
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let Args { day, year, legacy } = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
 
+    let root = match advent_of_code::input::crate_root() {
+        Ok(root) => root,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
     let day_padded = format!("{:02}", day);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let input_path = root.join(format!("src/{}/inputs/{}.txt", year, day_padded));
+    let example_path = root.join(format!("src/{}/examples/{}.txt", year, day_padded));
+    let module_path = root.join(format!("src/bin/{}_{}.rs", year, day_padded));
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,9 +139,18 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    let template = match legacy {
+        true => LEGACY_MODULE_TEMPLATE,
+        false => MODULE_TEMPLATE,
+    };
+    let module = template
+        .replace("{YEAR}", &year.to_string())
+        .replace("{DAY_PADDED}", &day_padded)
+        .replace("{DAY}", &day.to_string());
+
+    match file.write_all(module.as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {}", e);
@@ -88,7 +160,7 @@ fn main() {
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {}", e);
@@ -98,7 +170,7 @@ fn main() {
 
     match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create example file: {}", e);
@@ -108,7 +180,12 @@ fn main() {
 
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Put the expected answers of the example into \"{}\" and \"{}\", the tests fail without them.",
+        example_path.with_extension("part1").display(),
+        example_path.with_extension("part2").display()
+    );
+    println!(
+        "🎄 Type `cargo solve {}_{}` to run your solution.",
+        year, &day_padded
     );
 }
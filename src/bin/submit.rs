@@ -0,0 +1,308 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers;
+use advent_of_code::input::InputSource;
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process::{self, Command};
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+/// overrides the aoc-cli command, e.g. to point it at a fake script in tests.
+const AOC_COMMAND_ENV_VAR: &str = "AOC_COMMAND";
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: i16,
+    aoc_command: String,
+    yes: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        yes: args.contains("--yes"),
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(advent_of_code::default_year),
+        aoc_command: args
+            .opt_value_from_str("--aoc-command")?
+            .or_else(|| env::var(AOC_COMMAND_ENV_VAR).ok())
+            .unwrap_or_else(|| "aoc".into()),
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+    })
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    /// the part was solved before, so the answer is not checked.
+    AlreadySolved,
+    Unrecognized,
+}
+
+impl Outcome {
+    fn parse(output: &str) -> Self {
+        let output = output.to_lowercase();
+        if output.contains("that's the right answer") {
+            Outcome::Correct
+        } else if output.contains("you gave an answer too recently") {
+            Outcome::RateLimited
+        } else if output.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if output.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if output.contains("that's not the right answer") {
+            Outcome::Wrong
+        } else if output.contains("you don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unrecognized
+        }
+    }
+}
+
+fn submit(
+    aoc_command: &str,
+    year: i16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<(Outcome, String), String> {
+    let cmd_args = vec![
+        "--year".to_string(),
+        year.to_string(),
+        "--day".into(),
+        day.to_string(),
+        "submit".into(),
+        part.to_string(),
+        answer.to_string(),
+    ];
+
+    println!(
+        "Submitting answer with >{} {}",
+        aoc_command,
+        cmd_args.join(" ")
+    );
+
+    let cmd_output = Command::new(aoc_command)
+        .args(cmd_args)
+        .output()
+        .map_err(|e| format!("failed to spawn {}: {}", aoc_command, e))?;
+
+    let output = String::from_utf8_lossy(&cmd_output.stdout).to_string();
+    if !cmd_output.status.success() {
+        return Err(format!(
+            "{} exited with {}: {}{}",
+            aoc_command,
+            cmd_output.status,
+            output,
+            String::from_utf8_lossy(&cmd_output.stderr)
+        ));
+    }
+
+    Ok((Outcome::parse(&output), output))
+}
+
+fn confirm(prompt: &str) -> bool {
+    print!("{} [y/N] ", prompt);
+    io::stdout().flush().expect("could not flush stdout.");
+
+    let mut line = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map(|_| matches!(line.trim(), "y" | "Y" | "yes"))
+        .unwrap_or(false)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("example: `cargo submit 7 1`");
+            process::exit(1);
+        }
+    };
+
+    if args.part != 1 && args.part != 2 {
+        eprintln!("Part needs to be either 1 or 2.");
+        process::exit(1);
+    }
+
+    let solution = match SOLUTIONS
+        .iter()
+        .find(|solution| solution.year == args.year && solution.day == args.day)
+    {
+        Some(solution) => solution,
+        None => {
+            eprintln!("No solution found for day {} of {}.", args.day, args.year);
+            process::exit(1);
+        }
+    };
+
+    let input = match advent_of_code::try_read_file("inputs", args.year, args.day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    let params = match InputSource::Puzzle.params(args.year, args.day) {
+        Ok(params) => params,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    let result = (solution.solve)(&input, &params, &[args.part])
+        .remove(0)
+        .verify();
+    result.print();
+
+    let answer = match &result.answer {
+        Some(answer) if answer.canonical().contains('\n') => {
+            eprintln!("Could not read the letters of the grid, submit them by hand.");
+            process::exit(1);
+        }
+        Some(answer) => answer.canonical(),
+        None => {
+            eprintln!("Nothing to submit.");
+            process::exit(1);
+        }
+    };
+
+    let prompt = format!(
+        "Submit \"{}\" as the answer to day {} part {}?",
+        answer, args.day, args.part
+    );
+    if !args.yes && !confirm(&prompt) {
+        println!("Aborted.");
+        process::exit(0);
+    }
+
+    let outcome = match submit(&args.aoc_command, args.year, args.day, args.part, &answer) {
+        Ok((outcome, output)) => {
+            println!("{}", output.trim());
+            outcome
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("---");
+    match outcome {
+        Outcome::Correct => match answers::record_answer(args.year, args.day, args.part, &answer) {
+            Ok(path) => println!("🎄 Correct! Recorded answer in \"{}\".", path.display()),
+            Err(e) => {
+                eprintln!("Correct, but could not record answer: {}", e);
+                process::exit(1);
+            }
+        },
+        Outcome::Wrong => println!("❌ Wrong answer."),
+        Outcome::TooHigh => println!("❌ Wrong answer: too high."),
+        Outcome::TooLow => println!("❌ Wrong answer: too low."),
+        Outcome::RateLimited => println!("⏳ Rate-limited, wait a bit before submitting again."),
+        Outcome::AlreadySolved => println!("⭐ Already solved, the answer was not checked."),
+        Outcome::Unrecognized => println!("❓ Could not interpret the response."),
+    }
+
+    if outcome != Outcome::Correct {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+#[cfg(unix)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::PathBuf;
+
+    /// writes an executable stand-in for aoc-cli that logs its arguments and prints `response`.
+    fn fake_aoc(name: &str, response: &str) -> (PathBuf, PathBuf) {
+        let dir = env::temp_dir().join(format!("aoc_submit_{}_{}", name, process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let script_path = dir.join("aoc");
+        let args_path = dir.join("args");
+
+        let script = format!(
+            "#!/bin/sh\necho \"$@\" > {:?}\necho {:?}\n",
+            args_path, response
+        );
+        fs::write(&script_path, script).unwrap();
+        fs::set_permissions(&script_path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        (script_path, args_path)
+    }
+
+    #[test]
+    fn test_outcome_parse() {
+        assert_eq!(
+            Outcome::parse("That's the right answer! You are one gold star closer."),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too high."),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer; your answer is too low."),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            Outcome::parse("That's not the right answer. If you're stuck, ..."),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently. You have 42s left to wait."),
+            Outcome::RateLimited
+        );
+        assert_eq!(
+            Outcome::parse(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ),
+            Outcome::AlreadySolved
+        );
+        assert_eq!(Outcome::parse(""), Outcome::Unrecognized);
+    }
+
+    #[test]
+    fn test_submit_correct() {
+        let (aoc, args) = fake_aoc("correct", "That's the right answer!");
+        let (outcome, _) = submit(aoc.to_str().unwrap(), 2022, 7, 2, "24933642").unwrap();
+
+        assert_eq!(outcome, Outcome::Correct);
+        assert_eq!(
+            fs::read_to_string(args).unwrap().trim(),
+            "--year 2022 --day 7 submit 2 24933642"
+        );
+    }
+
+    #[test]
+    fn test_submit_too_low() {
+        let (aoc, _) = fake_aoc(
+            "too_low",
+            "That's not the right answer; your answer is too low.",
+        );
+        let (outcome, _) = submit(aoc.to_str().unwrap(), 2022, 1, 1, "1").unwrap();
+        assert_eq!(outcome, Outcome::TooLow);
+    }
+
+    #[test]
+    fn test_submit_missing_command() {
+        assert!(submit("/nonexistent/aoc", 2022, 1, 1, "1").is_err());
+    }
+}
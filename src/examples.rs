@@ -0,0 +1,226 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input;
+use crate::params::Params;
+use crate::solution::Solution;
+use crate::Answer;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// an example input `src/<year>/examples/NN[-<name>].txt`. the expected answers are stored next to
+/// it in `NN[-<name>].part1` and `NN[-<name>].part2`, a missing file means the part is not checked.
+/// its puzzle parameters are read from `NN[-<name>].params`, see `Params`.
+#[derive(Debug)]
+pub struct Example {
+    pub name: Option<String>,
+    pub path: PathBuf,
+    pub input: String,
+    pub expected: [Option<String>; 2],
+    pub params: Params,
+}
+
+impl Example {
+    fn load(path: PathBuf, name: Option<String>) -> Self {
+        let input = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("could not read \"{}\": {}", path.display(), e));
+        let expected = [1, 2].map(|part| read_expected(&path, part));
+        let params = Params::read(&path).unwrap_or_else(|e| panic!("{}", e));
+
+        Example {
+            name,
+            path,
+            input,
+            expected,
+            params,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        self.path
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        let index = usize::from(part).checked_sub(1)?;
+        self.expected.get(index)?.as_deref()
+    }
+}
+
+fn read_expected(example_path: &Path, part: u8) -> Option<String> {
+    fs::read_to_string(example_path.with_extension(format!("part{}", part)))
+        .ok()
+        .map(|answer| answer.trim_end().to_string())
+        .filter(|answer| !answer.is_empty())
+}
+
+/// the expected answer of `part` for the default example (`None`) or a named one.
+pub fn expected_answer(year: i16, day: u8, name: Option<&str>, part: u8) -> Option<String> {
+    let path = match name {
+        Some(name) => input::variant_path("examples", year, day, name),
+        None => input::puzzle_path("examples", year, day),
+    };
+    read_expected(&path.ok()?, part)
+}
+
+/// `Some(None)` for the default example `NN.txt`, `Some(Some(name))` for `NN-<name>.txt`.
+fn example_name(file_name: &str, day: u8) -> Option<Option<String>> {
+    let stem = file_name.strip_suffix(".txt")?;
+    let rest = stem.strip_prefix(&format!("{:02}", day))?;
+
+    match rest.strip_prefix('-') {
+        None if rest.is_empty() => Some(None),
+        Some(name) if !name.is_empty() => Some(Some(name.to_string())),
+        _ => None,
+    }
+}
+
+/// every example of a day, the default example first and the named ones sorted by name.
+pub fn discover(year: i16, day: u8) -> Vec<Example> {
+    let dir = input::puzzle_path("examples", year, day)
+        .unwrap_or_else(|e| panic!("{}", e))
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    let mut examples = fs::read_dir(&dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let file_name = entry.file_name().to_string_lossy().to_string();
+                    example_name(&file_name, day).map(|name| (name, entry.path()))
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    examples.sort();
+
+    examples
+        .into_iter()
+        .map(|(name, path)| Example::load(path, name))
+        .collect()
+}
+
+/// runs `solver` on every example that has an expected answer for `part` and panics with a
+/// summary of all mismatches. panics as well if no example has an expected answer, so that a
+/// missing `.partN` file cannot pass silently. returns the number of checked examples.
+#[track_caller]
+pub fn check<T: Into<Answer>>(
+    year: i16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> Option<T>,
+) -> usize {
+    check_examples(year, day, part, |example| solver(&example.input))
+}
+
+#[track_caller]
+fn check_examples<T: Into<Answer>>(
+    year: i16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&Example) -> Option<T>,
+) -> usize {
+    let examples = discover(year, day);
+    let checked = examples
+        .iter()
+        .filter_map(|example| example.expected(part).map(|expected| (example, expected)))
+        .collect::<Vec<_>>();
+
+    if checked.is_empty() {
+        panic!(
+            "no example of day {} has an expected answer for part {}",
+            day, part
+        );
+    }
+
+    let failures = checked
+        .iter()
+        .filter_map(
+            |(example, expected)| match solver(example).map(Into::<Answer>::into) {
+                Some(answer) if answer.matches(expected) => None,
+                Some(answer) => Some(format!(
+                    "{}: expected\n{}\nbut got\n{}",
+                    example.label(),
+                    expected,
+                    answer
+                )),
+                None => Some(format!(
+                    "{}: expected\n{}\nbut it was not solved",
+                    example.label(),
+                    expected
+                )),
+            },
+        )
+        .collect::<Vec<_>>();
+
+    if !failures.is_empty() {
+        panic!(
+            "part {} failed {} of {} examples:\n\n{}",
+            part,
+            failures.len(),
+            checked.len(),
+            failures.join("\n\n")
+        );
+    }
+
+    checked.len()
+}
+
+/// like `check`, for a day implementing `Solution`. every example is parsed with its own
+/// parameters, parse errors fail the check.
+#[track_caller]
+pub fn check_solution<S: Solution>(year: i16, day: u8, part: u8) -> usize {
+    check_examples(year, day, part, |example| -> Option<Answer> {
+        let parsed = S::parse(&example.input, &example.params)
+            .unwrap_or_else(|e| panic!("could not parse {}: {}", example.label(), e));
+        match part {
+            1 => S::part_one(&parsed).map(Into::into),
+            _ => S::part_two(&parsed).map(Into::into),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_name() {
+        assert_eq!(example_name("07.txt", 7), Some(None));
+        assert_eq!(example_name("07-large.txt", 7), Some(Some("large".into())));
+        assert_eq!(example_name("07-.txt", 7), None);
+        assert_eq!(example_name("07.part1", 7), None);
+        assert_eq!(example_name("17.txt", 7), None);
+        assert_eq!(example_name("070.txt", 7), None);
+    }
+
+    #[test]
+    fn test_discover() {
+        let examples = discover(2022, 9);
+        let names = examples
+            .iter()
+            .map(|example| example.name.as_deref())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, [None, Some("small")]);
+        assert_eq!(examples[0].expected(2), Some("36"));
+        assert_eq!(examples[0].expected(0), None);
+        assert_eq!(examples[0].expected(3), None);
+        assert_eq!(
+            expected_answer(2022, 9, Some("small"), 1),
+            Some("13".into())
+        );
+        assert_eq!(expected_answer(2022, 9, Some("missing"), 1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "no example of day 26 has an expected answer for part 1")]
+    fn test_check_without_expected_answers() {
+        check(2022, 26, 1, |input| Some(input.len()));
+    }
+}
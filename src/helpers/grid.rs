@@ -0,0 +1,323 @@
+//! a rectangular 2D grid with flat storage. positions are `(row, column)`, starting top left.
+use crate::solution::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// `(row, column)`.
+pub type Pos = (usize, usize);
+/// `(row delta, column delta)`.
+pub type Direction = (isize, isize);
+
+pub const UP: Direction = (-1, 0);
+pub const DOWN: Direction = (1, 0);
+pub const LEFT: Direction = (0, -1);
+pub const RIGHT: Direction = (0, 1);
+
+pub const DIRECTIONS4: [Direction; 4] = [UP, RIGHT, DOWN, LEFT];
+pub const DIRECTIONS8: [Direction; 8] = [UP, (-1, 1), RIGHT, (1, 1), DOWN, (1, -1), LEFT, (-1, -1)];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// fails if the rows are not all of the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, ParseError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        if let Some(index) = rows.iter().position(|row| row.len() != width) {
+            return Err(ParseError::new(format!(
+                "expected a row of {} cells, got {}",
+                width,
+                rows[index].len()
+            ))
+            .at_line(index));
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// parses one row per line, converting every character with `cell`.
+    pub fn parse_with(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(column, c)| {
+                        cell(c).ok_or_else(|| {
+                            ParseError::new(format!("unexpected `{}` in column {}", c, column + 1))
+                                .at_line(index)
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, column): Pos) -> bool {
+        row < self.height && column < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        match self.contains(pos) {
+            true => Some(&mut self.cells[pos.0 * self.width + pos.1]),
+            false => None,
+        }
+    }
+
+    /// `pos` moved by `direction`, if that is still inside the grid.
+    pub fn step(&self, (row, column): Pos, (d_row, d_column): Direction) -> Option<Pos> {
+        let pos = (
+            row.checked_add_signed(d_row)?,
+            column.checked_add_signed(d_column)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// the orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS4
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// the orthogonal and diagonal neighbours of `pos` inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS8
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// the positions from `pos` (exclusive) in `direction` up to the edge of the grid.
+    pub fn ray(&self, pos: Pos, direction: Direction) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(pos, direction), move |&pos| {
+            self.step(pos, direction)
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// `height` rows, so a grid without columns still has its (empty) rows.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        (0..self.height).map(move |row| self.row(row))
+    }
+
+    pub fn column(
+        &self,
+        column: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        (0..self.height).map(move |row| &self[(row, column)])
+    }
+
+    /// all positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// all cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.positions().zip(&self.cells)
+    }
+
+    /// the first position holding `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// every position holding `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// one line per row, every cell rendered by `f`.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut f).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside of the {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the {}x{} grid", pos, width, height))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(input, Some)
+    }
+}
+
+/// prints one line per row, without separators between cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse_with("123\n456", |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get((2, 0)), None);
+
+        let error = Grid::parse_with("12\n4x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(error.to_string(), "line 2: unexpected `x` in column 2");
+
+        let error = "ab\nc".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected a row of 2 cells, got 1"
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 3);
+        assert_eq!(
+            grid.neighbours8((0, 1)).collect::<Vec<_>>(),
+            [(0, 2), (1, 2), (1, 1), (1, 0), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = digits();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!(
+            grid.ray((1, 0), RIGHT).collect::<Vec<_>>(),
+            [(1, 1), (1, 2)]
+        );
+        assert_eq!(grid.ray((1, 0), LEFT).count(), 0);
+        assert_eq!(grid.ray((1, 0), (-1, 1)).collect::<Vec<_>>(), [(0, 1)]);
+    }
+
+    #[test]
+    fn test_empty_rows() {
+        let grid = Grid::from_rows(vec![vec![0u32; 0]; 3]).unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 3));
+        assert_eq!(grid.rows().len(), 3);
+        assert!(grid.rows().all(<[u32]>::is_empty));
+        assert_eq!(grid.to_string(), "\n\n");
+
+        assert_eq!(Grid::new(3, 0, 0).rows().len(), 0);
+    }
+
+    #[test]
+    fn test_find_and_print() {
+        let mut grid = "S.#\n.#E".parse::<Grid<char>>().unwrap();
+        assert_eq!(grid.find(&'E'), Some((1, 2)));
+        assert_eq!(grid.find_all(&'#').collect::<Vec<_>>(), [(0, 2), (1, 1)]);
+        assert_eq!(grid.find(&'x'), None);
+
+        grid[(0, 0)] = 'a';
+        assert_eq!(grid.to_string(), "a.#\n.#E");
+        assert_eq!(
+            grid.render(|&c| if c == '#' { '█' } else { ' ' }),
+            "  █\n █ "
+        );
+    }
+}
@@ -0,0 +1,240 @@
+//! sets of integers stored as sorted, inclusive ranges.
+use num::PrimInt;
+use std::ops::RangeInclusive;
+
+/// a set of integers, kept as sorted ranges that neither overlap nor touch:
+/// inserting `1..=3` and `4..=6` stores `1..=6`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { intervals: vec![] }
+    }
+
+    /// adds `range`, merging it with every range it overlaps or touches. empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // `saturating_add` makes a range ending at `T::max_value()` touch nothing after it.
+        let first = self
+            .intervals
+            .partition_point(|&(_, other_end)| other_end.saturating_add(T::one()) < start);
+        let last = self
+            .intervals
+            .partition_point(|&(other_start, _)| other_start <= end.saturating_add(T::one()));
+
+        if first < last {
+            start = start.min(self.intervals[first].0);
+            end = end.max(self.intervals[last - 1].1);
+        }
+        self.intervals.splice(first..last, [(start, end)]);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|&(_, end)| end < value);
+        self.intervals
+            .get(index)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// the stored ranges in ascending order.
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = RangeInclusive<T>> + ExactSizeIterator + '_ {
+        self.intervals.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// the number of covered values, which can exceed `T`: all of `u8` holds 256 values. it
+    /// only overflows for a set of more than `u128::MAX` values of `i128` or `u128`.
+    pub fn len(&self) -> u128 {
+        self.intervals
+            .iter()
+            .map(|&(start, end)| match (start.to_i128(), end.to_i128()) {
+                (Some(start), Some(end)) => end.abs_diff(start) + 1,
+                // only values of `u128` do not fit in i128.
+                _ => end.to_u128().unwrap() - start.to_u128().unwrap() + 1,
+            })
+            .sum()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.intervals.get(i), other.intervals.get(j))
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                intersection.push((start, end));
+            }
+            // the range ending first cannot overlap anything after the other one.
+            match a_end < b_end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+
+        IntervalSet {
+            intervals: intersection,
+        }
+    }
+
+    /// the values of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = vec![];
+        let mut j = 0;
+
+        for &(start, end) in &self.intervals {
+            // skip the ranges of `other` entirely before this one.
+            while other
+                .intervals
+                .get(j)
+                .is_some_and(|&(_, b_end)| b_end < start)
+            {
+                j += 1;
+            }
+
+            let mut start = Some(start);
+            for &(b_start, b_end) in other.intervals[j..]
+                .iter()
+                .take_while(|&&(b_start, _)| b_start <= end)
+            {
+                let Some(current) = start else { break };
+                if b_start > current {
+                    difference.push((current, b_start - T::one()));
+                }
+                start = match b_end < end {
+                    true => Some(current.max(b_end + T::one())),
+                    false => None,
+                };
+            }
+            if let Some(start) = start {
+                difference.push((start, end));
+            }
+        }
+
+        IntervalSet {
+            intervals: difference,
+        }
+    }
+
+    /// the values of `self` inside `range`.
+    pub fn clip(&self, range: RangeInclusive<T>) -> Self {
+        self.intersection(&IntervalSet::from_iter([range]))
+    }
+
+    /// the ranges inside `within` that are not covered, in ascending order.
+    pub fn gaps(&self, within: RangeInclusive<T>) -> Vec<RangeInclusive<T>> {
+        IntervalSet::from_iter([within])
+            .difference(self)
+            .iter()
+            .collect()
+    }
+}
+
+impl<T: PrimInt> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+impl<T: PrimInt> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges(set: &IntervalSet<i64>) -> Vec<RangeInclusive<i64>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut intervals = set(&[10..=12, 1..=3, 20..=25]);
+        assert_eq!(ranges(&intervals), [1..=3, 10..=12, 20..=25]);
+
+        // touching ranges are merged, empty ones ignored.
+        intervals.insert(4..=5);
+        let (start, end) = (7, 6);
+        intervals.insert(start..=end);
+        assert_eq!(ranges(&intervals), [1..=5, 10..=12, 20..=25]);
+
+        intervals.insert(11..=21);
+        assert_eq!(ranges(&intervals), [1..=5, 10..=25]);
+        assert_eq!(intervals.len(), 21);
+        assert!(intervals.contains(10) && intervals.contains(5));
+        assert!(!intervals.contains(6) && !intervals.contains(26));
+
+        let mut bounded = IntervalSet::new();
+        bounded.insert(250u8..=255);
+        bounded.insert(0..=249);
+        assert_eq!(bounded.iter().collect::<Vec<_>>(), [0..=255]);
+        assert_eq!(bounded.len(), 256);
+    }
+
+    #[test]
+    fn test_len_of_full_domains() {
+        assert_eq!(set(&[i64::MIN..=i64::MAX]).len(), 1 << 64);
+        assert_eq!(set(&[i64::MIN..=-1, 1..=i64::MAX]).len(), (1 << 64) - 1);
+
+        let unsigned = IntervalSet::from_iter([0..=u128::MAX - 1]);
+        assert_eq!(unsigned.len(), u128::MAX);
+        assert_eq!(IntervalSet::<u32>::new().len(), 0);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[0..=10, 20..=30]);
+        let b = set(&[5..=25, 40..=41]);
+
+        assert_eq!(ranges(&a.union(&b)), [0..=30, 40..=41]);
+        assert_eq!(ranges(&a.intersection(&b)), [5..=10, 20..=25]);
+        assert_eq!(ranges(&a.difference(&b)), [0..=4, 26..=30]);
+        assert_eq!(ranges(&b.difference(&a)), [11..=19, 40..=41]);
+        assert_eq!(
+            ranges(&a.difference(&set(&[2..=3, 5..=5, 28..=40]))),
+            [0..=1, 4..=4, 6..=10, 20..=27]
+        );
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn test_clip_and_gaps() {
+        let intervals = set(&[-5..=2, 4..=8, 12..=30]);
+
+        assert_eq!(ranges(&intervals.clip(0..=20)), [0..=2, 4..=8, 12..=20]);
+        assert_eq!(intervals.gaps(0..=20), [3..=3, 9..=11]);
+        assert_eq!(intervals.gaps(-10..=40), [-10..=-6, 3..=3, 9..=11, 31..=40]);
+        assert!(intervals.gaps(13..=20).is_empty());
+        assert!(IntervalSet::<i64>::new().clip(0..=5).is_empty());
+    }
+}
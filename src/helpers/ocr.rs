@@ -0,0 +1,198 @@
+//! recognizes letters drawn in the 4x6 block font that puzzles use on screens, e.g. day 10 of 2022.
+//! letters are 4 pixels wide and separated by one empty column. `#` is a lit pixel.
+use std::fmt;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+const GLYPH_SPACING: usize = 1;
+
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// a glyph that is not part of the font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    /// 0-based index of the letter.
+    pub index: usize,
+    /// 0-based column of its leftmost pixel.
+    pub column: usize,
+    pub rendering: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// the grid cannot hold a row of letters, e.g. because it is not 6 pixels high.
+    Shape {
+        width: usize,
+        height: usize,
+    },
+    UnknownGlyphs(Vec<UnknownGlyph>),
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::Shape { width, height } => write!(
+                f,
+                "a {}x{} grid does not hold letters of the {}x{} font",
+                width, height, GLYPH_WIDTH, GLYPH_HEIGHT
+            ),
+            OcrError::UnknownGlyphs(glyphs) => {
+                write!(f, "unknown glyphs:")?;
+                for glyph in glyphs {
+                    write!(
+                        f,
+                        "\nletter {} at column {}:\n{}",
+                        glyph.index + 1,
+                        glyph.column,
+                        glyph.rendering.join("\n")
+                    )?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+/// decodes rows of `#` (lit) and any other character (unlit).
+pub fn recognize(rows: &[impl AsRef<str>]) -> Result<String, OcrError> {
+    let pixels = rows
+        .iter()
+        .map(|row| row.as_ref().chars().map(|c| c == '#').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    recognize_pixels(&pixels)
+}
+
+/// decodes a grid of lit pixels, row by row.
+pub fn recognize_pixels(pixels: &[Vec<bool>]) -> Result<String, OcrError> {
+    let width = pixels.first().map_or(0, Vec::len);
+    let height = pixels.len();
+    let stride = GLYPH_WIDTH + GLYPH_SPACING;
+
+    // the empty column after the last letter is optional.
+    if height != GLYPH_HEIGHT
+        || width < GLYPH_WIDTH
+        || pixels.iter().any(|row| row.len() != width)
+        || !matches!(width % stride, 0 | GLYPH_WIDTH)
+    {
+        return Err(OcrError::Shape { width, height });
+    }
+
+    let mut letters = String::new();
+    let mut unknown = vec![];
+
+    for (index, column) in (0..width).step_by(stride).enumerate() {
+        let glyph = pixels
+            .iter()
+            .map(|row| &row[column..column + GLYPH_WIDTH])
+            .collect::<Vec<_>>();
+
+        let letter = FONT.iter().find(|(_, font_glyph)| {
+            font_glyph.iter().zip(&glyph).all(|(font_row, row)| {
+                font_row
+                    .chars()
+                    .zip(row.iter())
+                    .all(|(c, &lit)| (c == '#') == lit)
+            })
+        });
+
+        match letter {
+            Some((letter, _)) => letters.push(*letter),
+            None => unknown.push(UnknownGlyph {
+                index,
+                column,
+                rendering: glyph
+                    .iter()
+                    .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect())
+                    .collect(),
+            }),
+        }
+    }
+
+    match unknown.is_empty() {
+        true => Ok(letters),
+        false => Err(OcrError::UnknownGlyphs(unknown)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recognize() {
+        let rows = [
+            "####.#..#.####.####.####.#..#...##.####.",
+            "#....#..#....#.#.......#.#..#....#....#.",
+            "###..####...#..###....#..####....#...#..",
+            "#....#..#..#...#.....#...#..#....#..#...",
+            "#....#..#.#....#....#....#..#.#..#.#....",
+            "####.#..#.####.#....####.#..#..##..####.",
+        ];
+        assert_eq!(recognize(&rows), Ok("EHZFZHJZ".into()));
+
+        // the trailing empty column may be cut off.
+        let rows = rows.map(|row| &row[..row.len() - 1]);
+        assert_eq!(recognize(&rows), Ok("EHZFZHJZ".into()));
+    }
+
+    #[test]
+    fn test_recognize_unknown_glyphs() {
+        let rows = [
+            "####.#...",
+            "#....##..",
+            "###..#.#.",
+            "#....#..#",
+            "#....#...",
+            "####.#...",
+        ];
+
+        let error = recognize(&rows).unwrap_err();
+        assert_eq!(
+            error,
+            OcrError::UnknownGlyphs(vec![UnknownGlyph {
+                index: 1,
+                column: 5,
+                rendering: ["#...", "##..", "#.#.", "#..#", "#...", "#..."]
+                    .map(String::from)
+                    .to_vec(),
+            }])
+        );
+        assert!(error
+            .to_string()
+            .starts_with("unknown glyphs:\nletter 2 at column 5:\n#...\n"));
+    }
+
+    #[test]
+    fn test_recognize_shape() {
+        assert_eq!(
+            recognize(&["#.", ".#"]),
+            Err(OcrError::Shape {
+                width: 2,
+                height: 2
+            })
+        );
+        assert!(recognize(&[".##.."; 7]).is_err());
+        assert!(recognize(&[] as &[&str]).is_err());
+    }
+}
@@ -0,0 +1,275 @@
+//! shortest path searches over any node type. nodes are expanded through a successor closure,
+//! every search accepts several start nodes and stops at the first node accepted by `is_goal`.
+//! pass `|_| false` to explore everything that is reachable.
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// the outcome of a search: the distance of every reached node and how it was reached.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    distances: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    /// the goal the search stopped at, if any.
+    pub goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Self {
+        Search {
+            distances: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// the distance from the closest start node, `None` if `node` was not reached.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// the distance of the goal the search stopped at.
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// the nodes from a start node up to `node`, both included.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last()?) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// the path to the goal the search stopped at.
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+
+    /// every reached node with its distance, in no particular order.
+    pub fn reached(&self) -> impl Iterator<Item = (&N, C)> + '_ {
+        self.distances
+            .iter()
+            .map(|(node, &distance)| (node, distance))
+    }
+}
+
+/// breadth-first search where every step costs 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = search.distances.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        let distance = search.distances[&node] + 1;
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = search.distances.entry(next.clone()) {
+                entry.insert(distance);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// dijkstra's algorithm. `successors` yields the neighbours with the non-negative cost of the step.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` estimates the remaining cost to a goal and must never overestimate it,
+/// otherwise the returned distances are not guaranteed to be minimal.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    // the heap holds indices into `nodes`, so nodes do not need to be ordered themselves.
+    let mut nodes = vec![];
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = search.distances.entry(start.clone()) {
+            entry.insert(C::default());
+            heap.push(Reverse((heuristic(&start), C::default(), nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, distance, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        // a shorter way to this node was found after it was queued.
+        if search.distances[&node] < distance {
+            continue;
+        }
+
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (next, cost) in successors(&node) {
+            let next_distance = distance + cost;
+            let improved = match search.distances.get(&next) {
+                Some(&known) => next_distance < known,
+                None => true,
+            };
+
+            if improved {
+                search.distances.insert(next.clone(), next_distance);
+                search.parents.insert(next.clone(), node.clone());
+                heap.push(Reverse((
+                    next_distance + heuristic(&next),
+                    next_distance,
+                    nodes.len(),
+                )));
+                nodes.push(next);
+            }
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::grid::{Grid, Pos};
+
+    fn maze() -> Grid<char> {
+        ["S..#....", ".#.#.##.", ".#...#..", ".####.#.", "......#E"]
+            .join("\n")
+            .parse()
+            .unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<char>, pos: Pos) -> Vec<Pos> {
+        grid.neighbours4(pos)
+            .filter(|&next| grid[next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let (start, end) = (grid.find(&'S').unwrap(), grid.find(&'E').unwrap());
+
+        let search = bfs(
+            [start],
+            |&pos| open_neighbours(&grid, pos),
+            |&pos| pos == end,
+        );
+        assert_eq!(search.goal, Some(end));
+        assert_eq!(search.goal_distance(), Some(15));
+
+        let path = search.goal_path().unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!((path[0], path[15]), (start, end));
+        assert!(path
+            .windows(2)
+            .all(|step| grid.neighbours4(step[0]).any(|pos| pos == step[1])));
+
+        let search = bfs([start], |&pos| open_neighbours(&grid, pos), |_| false);
+        assert_eq!(search.goal, None);
+        assert_eq!(search.reached().count(), 27);
+        assert_eq!(search.distance(&(0, 3)), None);
+    }
+
+    #[test]
+    fn test_bfs_multi_source() {
+        let grid = maze();
+        let end = grid.find(&'E').unwrap();
+        let starts = [(0, 0), (0, 4)];
+
+        let search = bfs(
+            starts,
+            |&pos| open_neighbours(&grid, pos),
+            |&pos| pos == end,
+        );
+        assert_eq!(search.goal_distance(), Some(7));
+        assert_eq!(search.goal_path().unwrap()[0], (0, 4));
+        assert_eq!(search.path(&(0, 0)), Some(vec![(0, 0)]));
+    }
+
+    #[test]
+    fn test_weighted() {
+        // a -> b -> d is shorter than the direct edge a -> d.
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('c', 4), ('d', 10)]),
+            ('b', vec![('c', 1), ('d', 5)]),
+            ('c', vec![('d', 1)]),
+            ('d', vec![]),
+        ]);
+        let successors = |node: &char| edges[node].clone();
+
+        let search = dijkstra(['a'], successors, |&node| node == 'd');
+        assert_eq!(search.goal_distance(), Some(3));
+        assert_eq!(search.goal_path(), Some(vec!['a', 'b', 'c', 'd']));
+
+        let search = dijkstra(['a'], successors, |_| false);
+        assert_eq!(search.distance(&'c'), Some(2));
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let grid = maze();
+        let (start, end) = (grid.find(&'S').unwrap(), grid.find(&'E').unwrap());
+        let successors = |&pos: &Pos| {
+            open_neighbours(&grid, pos)
+                .into_iter()
+                .map(|next| (next, 1u32))
+                .collect::<Vec<_>>()
+        };
+        let manhattan =
+            |&(row, column): &Pos| (row.abs_diff(end.0) + column.abs_diff(end.1)) as u32;
+
+        let with_heuristic = astar([start], successors, manhattan, |&pos| pos == end);
+        let without = dijkstra([start], successors, |&pos| pos == end);
+        assert_eq!(with_heuristic.goal_distance(), Some(15));
+        assert_eq!(with_heuristic.goal_distance(), without.goal_distance());
+        assert!(with_heuristic.reached().count() <= without.reached().count());
+    }
+}
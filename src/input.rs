@@ -0,0 +1,265 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::params::Params;
+use crate::solution::ParseError;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum InputError {
+    /// the crate root could not be located, neither via cargo nor from the working directory.
+    WrongCwd {
+        cwd: Option<PathBuf>,
+    },
+    Missing {
+        path: PathBuf,
+        hint: String,
+    },
+    Empty {
+        path: PathBuf,
+        hint: String,
+    },
+    Unreadable {
+        path: PathBuf,
+        source: io::Error,
+    },
+    InvalidParams {
+        path: PathBuf,
+        error: ParseError,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::WrongCwd { cwd } => write!(
+                f,
+                "could not locate the crate root from \"{}\". Run the command from within the repository.",
+                cwd.as_deref().unwrap_or(Path::new("?")).display()
+            ),
+            InputError::Missing { path, hint } => {
+                write!(f, "\"{}\" does not exist. {}", path.display(), hint)
+            }
+            InputError::Empty { path, hint } => {
+                write!(f, "\"{}\" is empty. {}", path.display(), hint)
+            }
+            InputError::Unreadable { path, source } => {
+                write!(f, "could not read \"{}\": {}", path.display(), source)
+            }
+            InputError::InvalidParams { path, error } => {
+                write!(f, "invalid parameters in \"{}\": {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Unreadable { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+fn is_crate_root(path: &Path) -> bool {
+    path.join("Cargo.toml").is_file() && path.join("src").is_dir()
+}
+
+/// resolves the crate root independently of the working directory: `CARGO_MANIFEST_DIR` is
+/// set by `cargo run` / `cargo test`, the compile-time value covers binaries run directly.
+/// the working directory is only used as a last resort.
+pub fn crate_root() -> Result<PathBuf, InputError> {
+    let cwd = env::current_dir().ok();
+
+    env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .into_iter()
+        .chain([PathBuf::from(env!("CARGO_MANIFEST_DIR"))])
+        .chain(cwd.clone())
+        .find(|path| is_crate_root(path))
+        .ok_or(InputError::WrongCwd { cwd })
+}
+
+/// path of `src/<year>/<folder>/<day>.txt`.
+pub fn puzzle_path(folder: &str, year: i16, day: u8) -> Result<PathBuf, InputError> {
+    Ok(crate_root()?
+        .join("src")
+        .join(year.to_string())
+        .join(folder)
+        .join(format!("{:02}.txt", day)))
+}
+
+/// path of the named variant `src/<year>/<folder>/<day>-<name>.txt`, e.g. an additional example.
+pub fn variant_path(folder: &str, year: i16, day: u8, name: &str) -> Result<PathBuf, InputError> {
+    Ok(puzzle_path(folder, year, day)?.with_file_name(format!("{:02}-{}.txt", day, name)))
+}
+
+fn hint(folder: &str, year: i16, day: u8) -> String {
+    let year_arg = match year == crate::default_year() {
+        true => String::new(),
+        false => format!(" --year {}", year),
+    };
+
+    match folder {
+        "inputs" => format!("Run `cargo download {}{}` first.", day, year_arg),
+        "examples" => format!("Paste the example of day {} into it.", day),
+        _ => format!("Create it for day {}.", day),
+    }
+}
+
+pub fn try_read_file(folder: &str, year: i16, day: u8) -> Result<String, InputError> {
+    read_puzzle_file(puzzle_path(folder, year, day)?, hint(folder, year, day))
+}
+
+fn read_puzzle_file(path: PathBuf, hint: String) -> Result<String, InputError> {
+    match fs::read_to_string(&path) {
+        Ok(contents) if contents.trim().is_empty() => Err(InputError::Empty { path, hint }),
+        Ok(contents) => Ok(contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(InputError::Missing { path, hint }),
+        Err(source) => Err(InputError::Unreadable { path, source }),
+    }
+}
+
+pub fn try_read_variant(
+    folder: &str,
+    year: i16,
+    day: u8,
+    name: &str,
+) -> Result<String, InputError> {
+    let hint = format!("Create it for day {}.", day);
+    read_puzzle_file(variant_path(folder, year, day, name)?, hint)
+}
+
+/// where a day binary reads its input from, see `runner::RunOptions`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// `src/<year>/inputs/<day>.txt`.
+    #[default]
+    Puzzle,
+    /// `src/<year>/examples/<day>.txt`, or `<day>-<name>.txt` for a named example.
+    Example(Option<String>),
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn read(&self, year: i16, day: u8) -> Result<String, InputError> {
+        match self {
+            InputSource::Puzzle => try_read_file("inputs", year, day),
+            InputSource::Example(None) => try_read_file("examples", year, day),
+            InputSource::Example(Some(name)) => try_read_variant("examples", year, day, name),
+            InputSource::Path(path) => {
+                read_puzzle_file(path.clone(), "Check the path passed to --input.".into())
+            }
+            InputSource::Stdin => {
+                let mut contents = String::new();
+                let path = PathBuf::from("<stdin>");
+                match io::stdin().read_to_string(&mut contents) {
+                    Ok(_) if contents.trim().is_empty() => Err(InputError::Empty {
+                        path,
+                        hint: "Pipe the input into the command.".into(),
+                    }),
+                    Ok(_) => Ok(contents),
+                    Err(source) => Err(InputError::Unreadable { path, source }),
+                }
+            }
+        }
+    }
+
+    /// the parameters stored next to the input, see `Params`. input from stdin has none.
+    pub fn params(&self, year: i16, day: u8) -> Result<Params, InputError> {
+        let path = match self {
+            InputSource::Puzzle => puzzle_path("inputs", year, day)?,
+            InputSource::Example(None) => puzzle_path("examples", year, day)?,
+            InputSource::Example(Some(name)) => variant_path("examples", year, day, name)?,
+            InputSource::Path(path) => path.clone(),
+            InputSource::Stdin => return Ok(Params::default()),
+        };
+        Params::read(&path)
+    }
+}
+
+/// reads `src/<year>/<folder>/<day>.txt`, panicking with a descriptive message on failure.
+pub fn read_file(folder: &str, year: i16, day: u8) -> String {
+    try_read_file(folder, year, day).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// reads `src/<year>/<folder>/<day>-<name>.txt`, panicking with a descriptive message on failure.
+pub fn read_variant(folder: &str, year: i16, day: u8, name: &str) -> String {
+    try_read_variant(folder, year, day, name).unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crate_root() {
+        let root = crate_root().unwrap();
+        assert!(root.join("src").join("lib.rs").is_file());
+    }
+
+    #[test]
+    fn test_variant_path() {
+        let path = variant_path("examples", 2022, 9, "small").unwrap();
+        assert!(path.ends_with("src/2022/examples/09-small.txt"));
+    }
+
+    #[test]
+    fn test_try_read_file_missing() {
+        let error = try_read_file("inputs", 1999, 7).unwrap_err();
+        assert!(matches!(error, InputError::Missing { .. }));
+        assert!(error
+            .to_string()
+            .ends_with("Run `cargo download 7 --year 1999` first."));
+    }
+
+    #[test]
+    fn test_input_source_read() {
+        let example = InputSource::Example(Some("small".into()))
+            .read(2022, 9)
+            .unwrap();
+        assert!(example.starts_with("R 4\nU 4\n"));
+
+        let path = crate_root().unwrap().join("src/2022/examples/09.txt");
+        assert_eq!(
+            InputSource::Path(path).read(2022, 9).unwrap(),
+            InputSource::Example(None).read(2022, 9).unwrap()
+        );
+
+        let error = InputSource::Path("/nonexistent/input.txt".into())
+            .read(2022, 9)
+            .unwrap_err();
+        assert!(matches!(error, InputError::Missing { .. }));
+    }
+
+    #[test]
+    fn test_input_source_params() {
+        let params = InputSource::Example(None).params(2022, 15).unwrap();
+        assert_eq!(params.get::<i64>("Y_END"), Ok(Some(20)));
+
+        let path = crate_root().unwrap().join("src/2022/examples/15.txt");
+        assert_eq!(InputSource::Path(path).params(2022, 15).unwrap(), params);
+        assert_eq!(
+            InputSource::Stdin.params(2022, 15).unwrap(),
+            Params::default()
+        );
+    }
+
+    #[test]
+    fn test_try_read_file_empty() {
+        let path = env::temp_dir().join(format!("aoc_input_empty_{}.txt", std::process::id()));
+        fs::write(&path, "\n").unwrap();
+
+        let error = read_puzzle_file(path, hint("inputs", 2022, 7)).unwrap_err();
+        assert!(matches!(error, InputError::Empty { .. }));
+        assert!(error.to_string().ends_with("Run `cargo download 7` first."));
+    }
+}
@@ -3,92 +3,557 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use answers::Verdict;
+use bench::BenchStats;
+use helpers::ocr::OcrError;
+use input::InputSource;
+use num::{BigInt, BigUint, ToPrimitive};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::env;
-use std::fs;
+use std::fmt::{self, Display};
+use std::time::Duration;
 
+pub mod answers;
+pub mod bench;
+pub mod examples;
 pub mod helpers;
+pub mod input;
+pub mod params;
+pub mod runner;
+pub mod solution;
+pub mod visualize;
+
+pub use input::{read_file, read_variant, try_read_file, try_read_variant, InputError};
+pub use params::Params;
+pub use solution::{ParseError, Solution};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
+/// the year puzzles are fetched for, unless one is passed explicitly.
+/// can be configured through the `AOC_YEAR` env var, e.g. in `.cargo/config`.
+pub const YEAR_ENV_VAR: &str = "AOC_YEAR";
+pub const DEFAULT_YEAR: i16 = 2022;
+
+/// setting this env var (to anything but `0`) has the same effect as passing `--json`.
+pub const JSON_ENV_VAR: &str = "AOC_JSON";
+pub const JSON_FLAG: &str = "--json";
+
+/// answer of a part. solvers may return anything that converts into it, e.g. integers or strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    /// only used for values that do not fit into an `i128`.
+    BigInteger(BigInt),
+    Text(String),
+    /// a picture drawn row by row, e.g. letters on a screen made of `#` and `.`.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    pub fn grid(rows: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Answer::Grid(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// the letters drawn by a grid answer, if all of them could be recognized.
+    pub fn ocr(&self) -> Option<String> {
+        match self {
+            Answer::Grid(rows) => helpers::ocr::recognize(rows).ok(),
+            _ => None,
+        }
+    }
+
+    /// the form that is submitted, stored in `src/<year>/answers/NN.txt` and compared against.
+    /// grids are read via OCR, and fall back to their raw rendering.
+    pub fn canonical(&self) -> String {
+        self.ocr().unwrap_or_else(|| self.to_string())
+    }
+
+    /// a single line describing the answer. for grids the recognized letters, or the grid size
+    /// and the columns of glyphs that could not be recognized.
+    pub fn summary(&self) -> String {
+        let rows = match self {
+            Answer::Grid(rows) => rows,
+            answer => return answer.to_string(),
+        };
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        match helpers::ocr::recognize(rows) {
+            Ok(letters) => letters,
+            Err(OcrError::UnknownGlyphs(glyphs)) => format!(
+                "{}x{} grid, unknown glyphs at columns {}",
+                width,
+                rows.len(),
+                glyphs
+                    .iter()
+                    .map(|glyph| glyph.column.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Err(OcrError::Shape { .. }) => format!("{}x{} grid", width, rows.len()),
+        }
+    }
+
+    /// whether `expected` is this answer. grids match both their letters and their raw rendering.
+    pub fn matches(&self, expected: &str) -> bool {
+        let expected = normalize(expected);
+        normalize(&self.canonical()) == expected || normalize(&self.to_string()) == expected
+    }
+}
+
+/// trims trailing whitespace of every line and surrounding empty lines.
+fn normalize(answer: &str) -> String {
+    answer
+        .trim_matches('\n')
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::BigInteger(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! integer_answer {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                Answer::Integer(value as i128)
             }
+        })*
+    };
+}
+
+integer_answer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        BigInt::from(value).into()
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        match value.to_i128() {
+            Some(value) => Answer::Integer(value),
+            None => Answer::BigInteger(value),
         }
+    }
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        BigInt::from(value).into()
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.into())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Answer::Text(c.into())
+    }
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// integers become JSON numbers as long as common JSON parsers can represent them exactly,
+/// larger ones are strings. text is a string and grids are an array of rows.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(value) => match (i64::try_from(*value), u64::try_from(*value)) {
+                (Ok(value), _) => serializer.serialize_i64(value),
+                (_, Ok(value)) => serializer.serialize_u64(value),
+                _ => serializer.serialize_str(&value.to_string()),
+            },
+            Answer::BigInteger(value) => serializer.serialize_str(&value.to_string()),
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Grid(rows) => rows.serialize(serializer),
+        }
+    }
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Signed(i64),
+            Unsigned(u64),
+            Text(String),
+            Grid(Vec<String>),
+        }
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Signed(value) => value.into(),
+            Repr::Unsigned(value) => value.into(),
+            Repr::Text(text) => {
+                let integer = text.parse::<BigInt>().ok();
+                match integer.filter(|value| value.to_i64().is_none() && value.to_u64().is_none()) {
+                    Some(value) => value.into(),
+                    None => Answer::Text(text),
+                }
+            }
+            Repr::Grid(rows) => Answer::Grid(rows),
+        })
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// machine-readable record of a single solved (or unsolved) part.
+/// printed as one JSON object per line when json output is enabled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    /// median of all samples in bench mode.
+    pub duration_ns: u64,
+    pub solved: bool,
+    /// set when the solver panicked instead of returning.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bench: Option<BenchStats>,
+    /// time spent parsing the input, shared by both parts of a `Solution`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse_ns: Option<u64>,
+    /// set once the answer was compared against `src/<year>/answers/NN.txt`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<Verdict>,
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
+impl PartResult {
+    pub fn new(year: i16, day: u8, part: u8, answer: Option<Answer>, elapsed: Duration) -> Self {
+        PartResult {
+            year,
+            day,
+            part,
+            solved: answer.is_some(),
+            answer,
+            duration_ns: elapsed.as_nanos().try_into().unwrap_or(u64::MAX),
+            error: None,
+            bench: None,
+            parse_ns: None,
+            verdict: None,
+        }
+    }
+
+    pub fn benched(
+        year: i16,
+        day: u8,
+        part: u8,
+        answer: Option<Answer>,
+        stats: BenchStats,
+    ) -> Self {
+        PartResult {
+            bench: Some(stats),
+            ..PartResult::new(year, day, part, answer, stats.median())
+        }
+    }
+
+    pub fn failed(year: i16, day: u8, part: u8, error: String) -> Self {
+        PartResult {
+            error: Some(error),
+            ..PartResult::new(year, day, part, None, Duration::ZERO)
+        }
+    }
+
+    pub fn verify(self) -> Self {
+        let expected = answers::expected_answer(self.year, self.day, self.part);
+        PartResult {
+            verdict: Some(answers::verdict(self.answer.as_ref(), expected)),
+            ..self
+        }
+    }
+
+    /// verifies against the answers that belong to `source`: the stored answers for the puzzle
+    /// input, the expected answers next to an example. custom inputs are not verified.
+    pub fn verify_against(self, source: &InputSource) -> Self {
+        let expected = match source {
+            InputSource::Puzzle => return self.verify(),
+            InputSource::Example(name) => {
+                examples::expected_answer(self.year, self.day, name.as_deref(), self.part)
+            }
+            InputSource::Path(_) | InputSource::Stdin => return self,
+        };
+        PartResult {
+            verdict: Some(answers::verdict(self.answer.as_ref(), expected)),
+            ..self
+        }
+    }
+
+    /// a known answer that is no longer produced.
+    pub fn is_regression(&self) -> bool {
+        matches!(self.verdict, Some(Verdict::Wrong { .. }))
+    }
+
+    fn verdict_label(&self) -> String {
+        match &self.verdict {
+            Some(Verdict::Wrong { expected }) => format!(" ❌ (expected: {})", expected),
+            Some(verdict) => format!(" {}", verdict.symbol()),
+            None => String::new(),
+        }
+    }
+
+    pub fn with_parse_time(self, parse_time: Duration) -> Self {
+        PartResult {
+            parse_ns: Some(parse_time.as_nanos().try_into().unwrap_or(u64::MAX)),
+            ..self
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.duration_ns)
+    }
+
+    pub fn parse_time(&self) -> Option<Duration> {
+        self.parse_ns.map(Duration::from_nanos)
+    }
+
+    fn parse_label(&self) -> String {
+        match self.parse_time() {
+            Some(parse_time) => format!(", parse: {:.2?}", parse_time),
+            None => String::new(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("could not serialize part result")
+    }
+
+    /// returns `None` for lines that are not a part result, e.g. debug output of a solution.
+    pub fn from_json(line: &str) -> Option<Self> {
+        serde_json::from_str(line).ok()
+    }
+
+    pub fn print(&self) {
+        if json_output() {
+            println!("{}", self.to_json());
+            return;
+        }
+
+        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, self.part, ANSI_RESET);
+        // grids are rendered as a block, followed by the usual line with the recognized letters.
+        if let (Some(answer @ Answer::Grid(_)), None) = (&self.answer, &self.error) {
+            println!("{}", answer);
+        }
+        match (&self.answer, &self.error) {
+            (_, Some(error)) => {
+                println!(
+                    "{}failed:{} {}{}",
+                    ANSI_BOLD,
+                    ANSI_RESET,
+                    error,
+                    self.verdict_label()
+                );
+            }
+            (Some(answer), None) if self.bench.is_some() => {
+                let stats = self.bench.unwrap();
+                println!(
+                    "{}{} {}(median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, samples: {}{}){}",
+                    answer.summary(),
+                    self.verdict_label(),
+                    ANSI_ITALIC,
+                    stats.median(),
+                    stats.mean(),
+                    stats.stddev(),
+                    stats.min(),
+                    stats.samples,
+                    self.parse_label(),
+                    ANSI_RESET
+                );
+            }
+            (Some(answer), None) => {
+                println!(
+                    "{}{} {}(elapsed: {:.2?}{}){}",
+                    answer.summary(),
+                    self.verdict_label(),
+                    ANSI_ITALIC,
+                    self.elapsed(),
+                    self.parse_label(),
+                    ANSI_RESET
+                );
+            }
+            (None, None) => {
+                println!("not solved.{}", self.verdict_label())
             }
         }
-    })
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
+pub fn json_output() -> bool {
+    env::args().skip(1).any(|arg| arg == JSON_FLAG)
+        || env::var(JSON_ENV_VAR).is_ok_and(|val| !val.is_empty() && val != "0")
+}
+
+pub fn default_year() -> i16 {
+    env::var(YEAR_ENV_VAR)
+        .ok()
+        .and_then(|year| year.parse().ok())
+        .unwrap_or(DEFAULT_YEAR)
+}
+
+/// derives year and day from the name of the running solution binary (e.g. `2022_07`).
+/// binaries named after the day only (e.g. `07`) belong to the default year.
+pub fn puzzle_from_bin_name(bin_name: &str) -> (i16, u8) {
+    let parsed = match bin_name.split_once('_') {
+        Some((year, day)) => year.parse().ok().zip(day.parse().ok()),
+        None => bin_name.parse().ok().map(|day| (default_year(), day)),
+    };
+    parsed.expect("solution binaries need to be named after their year and day, e.g. `2022_07`")
+}
+
+/// solves a single part and prints the result. the optional last argument is the
+/// `input::InputSource` the input was read from, which decides what the answer is verified against.
+#[macro_export]
+macro_rules! solve {
+    ($part:expr, $solver:ident, $input:expr) => {{
+        advent_of_code::solve!(
+            $part,
+            $solver,
+            $input,
+            &advent_of_code::input::InputSource::Puzzle
+        )
+    }};
+    ($part:expr, $solver:ident, $input:expr, $source:expr) => {{
+        let (year, day) = advent_of_code::puzzle_from_bin_name(env!("CARGO_BIN_NAME"));
+        let result = advent_of_code::runner::measure(year, day, $part, $solver, $input)
+            .verify_against($source);
+        result.print();
+        result
+    }};
+}
+
+/// registers a day for the in-process runner and generates its `main`. either the free functions
+/// `part_one` / `part_two` of the module, or a type implementing `advent_of_code::Solution`.
+/// example: `advent_of_code::solution!(2022, 7);` or `advent_of_code::solution!(2022, 7, Day07);`
+#[macro_export]
+macro_rules! solution {
+    // parses the options and reads the input and its parameters, exiting with a hint on failure.
+    (@input) => {{
+        let options = match advent_of_code::runner::RunOptions::from_env() {
+            Ok(options) => options,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {}", e);
+                std::process::exit(1);
+            }
+        };
+        if let Some(target) = &options.visualize {
+            if let Err(e) = advent_of_code::visualize::start(target.clone()) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        match options
+            .source
+            .read(YEAR, DAY)
+            .and_then(|input| Ok((input, options.source.params(YEAR, DAY)?)))
+        {
+            Ok((input, params)) => (options, input, params),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+    }};
+    // writes out recorded frames.
+    (@finish) => {{
+        if let Err(e) = advent_of_code::visualize::finish() {
+            eprintln!("{}", e);
+        }
     }};
+    ($year:expr, $day:expr) => {
+        pub const YEAR: i16 = $year;
+        pub const DAY: u8 = $day;
+
+        pub const SOLUTION: advent_of_code::runner::Day = advent_of_code::runner::Day {
+            year: YEAR,
+            day: DAY,
+            solve: |input, _params, parts| {
+                advent_of_code::runner::run_parts(YEAR, DAY, input, parts, part_one, part_two)
+            },
+        };
+
+        fn main() {
+            let (options, input, _params) = advent_of_code::solution!(@input);
+
+            let mut results = vec![];
+            if options.runs_part(1) {
+                results.push(advent_of_code::solve!(1, part_one, &input, &options.source));
+            }
+            if options.runs_part(2) {
+                results.push(advent_of_code::solve!(2, part_two, &input, &options.source));
+            }
+            advent_of_code::solution!(@finish);
+            advent_of_code::exit_on_regression(&results);
+        }
+    };
+    ($year:expr, $day:expr, $solution:ty) => {
+        pub const YEAR: i16 = $year;
+        pub const DAY: u8 = $day;
+
+        pub const SOLUTION: advent_of_code::runner::Day = advent_of_code::runner::Day {
+            year: YEAR,
+            day: DAY,
+            solve: |input, params, parts| {
+                advent_of_code::runner::run_solution::<$solution>(YEAR, DAY, input, params, parts, |r| r)
+            },
+        };
+
+        fn main() {
+            let (options, input, params) = advent_of_code::solution!(@input);
+
+            let results = advent_of_code::runner::run_solution::<$solution>(
+                YEAR,
+                DAY,
+                &input,
+                &params,
+                &options.parts(),
+                |result| {
+                    let result = result.verify_against(&options.source);
+                    result.print();
+                    result
+                },
+            );
+            advent_of_code::solution!(@finish);
+            if results.iter().any(|result| result.error.is_some()) {
+                std::process::exit(1);
+            }
+            advent_of_code::exit_on_regression(&results);
+        }
+    };
+}
+
+/// exits with a non-zero status if any known answer is not produced anymore.
+pub fn exit_on_regression(results: &[PartResult]) {
+    if results.iter().any(PartResult::is_regression) {
+        std::process::exit(1);
+    }
 }
 
 #[cfg(test)]
@@ -96,30 +561,91 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_part_result_json_roundtrip() {
+        let result = PartResult::new(
+            2022,
+            7,
+            2,
+            Some("24933642".into()),
+            Duration::from_micros(1450),
         );
+        let json = result.to_json();
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+        assert_eq!(
+            json,
+            r#"{"year":2022,"day":7,"part":2,"answer":"24933642","duration_ns":1450000,"solved":true}"#
         );
+        assert_eq!(PartResult::from_json(&json), Some(result));
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
+    fn letters_grid() -> Answer {
+        Answer::grid([
+            "####.#..#.",
+            "#....#..#.",
+            "###..####.",
+            "#....#..#.",
+            "#....#..#.",
+            "####.#..#.",
+        ])
+    }
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+    #[test]
+    fn test_answer_grid() {
+        let grid = letters_grid();
+        assert_eq!(grid.ocr(), Some("EH".into()));
+        assert_eq!(grid.canonical(), "EH");
+        assert_eq!(grid.summary(), "EH");
+        assert!(grid.to_string().starts_with("####.#..#.\n#....#..#.\n"));
+        assert!(grid.matches("EH\n"));
+        assert!(grid.matches(&grid.to_string()));
+
+        let unknown = Answer::grid(["#..", ".#.", "..#"]);
+        assert_eq!(unknown.ocr(), None);
+        assert_eq!(unknown.canonical(), "#..\n.#.\n..#");
+        assert_eq!(unknown.summary(), "3x3 grid");
+    }
+
+    #[test]
+    fn test_answer_from() {
+        assert_eq!(Answer::from(24933642_u64), Answer::Integer(24933642));
+        assert_eq!(Answer::from(BigInt::from(-7)), Answer::Integer(-7));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert!(matches!(Answer::from(u128::MAX), Answer::BigInteger(_)));
+        assert!(Answer::from("CMZ").matches(" CMZ "));
+        assert!(!Answer::from(12).matches("13"));
+    }
+
+    #[test]
+    fn test_answer_json() {
+        let answers = [
+            (Answer::from(-13), "-13".to_string()),
+            (Answer::from(u64::MAX), u64::MAX.to_string()),
+            (Answer::from(i128::MAX), format!("\"{}\"", i128::MAX)),
+            (Answer::from(u128::MAX), format!("\"{}\"", u128::MAX)),
+            (Answer::from("CMZ"), "\"CMZ\"".to_string()),
+            (Answer::grid(["#.", ".#"]), r##"["#.",".#"]"##.to_string()),
+        ];
+
+        for (answer, json) in answers {
+            assert_eq!(serde_json::to_string(&answer).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Answer>(&json).unwrap(), answer);
+        }
+    }
+
+    #[test]
+    fn test_puzzle_from_bin_name() {
+        assert_eq!(puzzle_from_bin_name("2021_07"), (2021, 7));
+        assert_eq!(puzzle_from_bin_name("07"), (default_year(), 7));
+    }
+
+    #[test]
+    fn test_part_result_unsolved() {
+        let result = PartResult::new(2022, 3, 1, None, Duration::from_nanos(74));
+        assert!(!result.solved);
+        assert_eq!(
+            PartResult::from_json(&result.to_json()).unwrap().answer,
+            None
         );
+        assert_eq!(PartResult::from_json("0 (elapsed: 74.13ns)"), None);
     }
 }
@@ -2,45 +2,112 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::runner;
+use advent_of_code::{PartResult, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, JSON_FLAG};
+use std::process;
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+/// `--year <year>` runs a single year (the configured default if omitted),
+/// `--all-years` runs every year that has at least one solution.
+fn parse_years() -> Result<Vec<i16>, String> {
+    let mut args = pico_args::Arguments::from_env();
+    // read by `advent_of_code::json_output`.
+    args.contains(JSON_FLAG);
+
+    let years = if args.contains("--all-years") {
+        let mut years = SOLUTIONS
+            .iter()
+            .map(|solution| solution.year)
+            .collect::<Vec<_>>();
+        years.sort();
+        years.dedup();
+        years
+    } else {
+        vec![args
+            .opt_value_from_str(["-y", "--year"])
+            .map_err(|e| e.to_string())?
+            .unwrap_or_else(advent_of_code::default_year)]
+    };
+
+    match args.finish().first() {
+        Some(arg) => Err(format!("unknown argument `{}`", arg.to_string_lossy())),
+        None => Ok(years),
+    }
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
+    let json_output = advent_of_code::json_output();
+
+    let years = match parse_years() {
+        Ok(years) => years,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let results = years
+        .into_iter()
+        .flat_map(|year| {
+            if !json_output {
+                println!("==========");
+                println!("{}🎄 {} 🎄{}", ANSI_BOLD, year, ANSI_RESET);
+                println!("==========");
             }
+
+            (1..=25).map(move |day| (year, day))
         })
-        .sum();
+        .flat_map(|(year, day)| {
+            let solution = SOLUTIONS
+                .iter()
+                .find(|solution| solution.year == year && solution.day == day);
+
+            if !json_output {
+                println!("----------");
+                println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+                println!("----------");
+            }
+
+            let results = match solution.map(|solution| solution.run()) {
+                None => {
+                    if !json_output {
+                        println!("Not solved.");
+                    }
+                    vec![]
+                }
+                Some(Ok(results)) => {
+                    results.iter().for_each(PartResult::print);
+                    results
+                }
+                // unsolved parts without a verdict do not count as regressions.
+                Some(Err(e)) => {
+                    let results = (1..=2)
+                        .map(|part| PartResult::failed(year, day, part, e.to_string()))
+                        .collect::<Vec<_>>();
+                    match json_output {
+                        true => results.iter().for_each(PartResult::print),
+                        false => println!("{}", e),
+                    }
+                    results
+                }
+            };
+
+            results
+        })
+        .collect::<Vec<_>>();
+    let total = runner::total_elapsed(&results);
+
+    if !json_output {
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    advent_of_code::exit_on_regression(&results);
 }
@@ -0,0 +1,179 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::InputError;
+use crate::solution::ParseError;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// parameters of a puzzle besides its input, e.g. the row that day 15 of 2022 asks about, which
+/// differs between the example and the real input. they are read from a sidecar file next to the
+/// input with the extension `.params`: one `NAME = value` per line, `#` starts a comment.
+/// solvers fall back to the values of the real puzzle for missing parameters.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    /// sets `name`, e.g. to pass parameters in tests.
+    pub fn with(mut self, name: &str, value: impl ToString) -> Self {
+        self.values.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// the parameters stored next to `input_path`. a missing file means no parameters.
+    pub fn read(input_path: &Path) -> Result<Self, InputError> {
+        let path = input_path.with_extension("params");
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => contents
+                .parse()
+                .map_err(|error| InputError::InvalidParams { path, error }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Params::default()),
+            Err(source) => Err(InputError::Unreadable { path, source }),
+        }
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>, ParseError>
+    where
+        T::Err: ToString,
+    {
+        self.values
+            .get(name)
+            .map(|value| {
+                value.parse().map_err(|e: T::Err| {
+                    ParseError::new(format!(
+                        "parameter {} = `{}`: {}",
+                        name,
+                        value,
+                        e.to_string()
+                    ))
+                })
+            })
+            .transpose()
+    }
+
+    /// `name`, or `default` if it is not set.
+    pub fn get_or<T: FromStr>(&self, name: &str, default: T) -> Result<T, ParseError>
+    where
+        T::Err: ToString,
+    {
+        Ok(self.get(name)?.unwrap_or(default))
+    }
+
+    /// `name` as one of the named `choices`, or `default` if it is not set. selects between the
+    /// approaches of a solution, e.g. `STRATEGY = row-scan`.
+    pub fn choice_or<T: Copy>(
+        &self,
+        name: &str,
+        choices: &[(&str, T)],
+        default: T,
+    ) -> Result<T, ParseError> {
+        let Some(value) = self.values.get(name) else {
+            return Ok(default);
+        };
+
+        choices
+            .iter()
+            .find(|(choice, _)| choice == value)
+            .map(|&(_, choice)| choice)
+            .ok_or_else(|| {
+                let names = choices
+                    .iter()
+                    .map(|(choice, _)| format!("`{}`", choice))
+                    .collect::<Vec<_>>();
+                let message = format!("expected one of {}", names.join(", "));
+                ParseError::new(format!("parameter {} = `{}`: {}", name, value, message))
+            })
+    }
+}
+
+impl FromStr for Params {
+    type Err = ParseError;
+
+    fn from_str(contents: &str) -> Result<Self, ParseError> {
+        let mut params = Params::default();
+
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            match line.split_once('=') {
+                Some((name, value)) if !name.trim().is_empty() => {
+                    params = params.with(name.trim(), value.trim());
+                }
+                _ => {
+                    let message = format!("expected `NAME = value`, got `{}`", line);
+                    return Err(ParseError::new(message).at_line(index));
+                }
+            }
+        }
+
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_params() {
+        let params = "# example bounds\nY_QUERY = 10\n\nY_END=20 # inclusive\n"
+            .parse::<Params>()
+            .unwrap();
+
+        assert_eq!(params, Params::new().with("Y_QUERY", 10).with("Y_END", 20));
+        assert_eq!(params.get::<i64>("Y_QUERY"), Ok(Some(10)));
+        assert_eq!(params.get::<i64>("Y_START"), Ok(None));
+        assert_eq!(params.get_or("Y_START", 0i64), Ok(0));
+
+        let error = params.get::<bool>("Y_END").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "parameter Y_END = `20`: provided string was not `true` or `false`"
+        );
+
+        let error = "Y_QUERY = 10\n= 5".parse::<Params>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2: expected `NAME = value`, got `= 5`"
+        );
+    }
+
+    #[test]
+    fn test_choice_params() {
+        let choices = [("fast", 1), ("slow", 2)];
+        let params = Params::new().with("MODE", "slow").with("OTHER", "walk");
+
+        assert_eq!(params.choice_or("MODE", &choices, 1), Ok(2));
+        assert_eq!(params.choice_or("UNSET", &choices, 1), Ok(1));
+
+        let error = params.choice_or("OTHER", &choices, 1).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "parameter OTHER = `walk`: expected one of `fast`, `slow`"
+        );
+    }
+
+    #[test]
+    fn test_read_params() {
+        let root = crate::input::crate_root().unwrap();
+        let params = Params::read(&root.join("src/2022/examples/15.txt")).unwrap();
+        assert_eq!(params.get::<i64>("Y_QUERY"), Ok(Some(10)));
+
+        let params = Params::read(&root.join("src/2022/examples/01.txt")).unwrap();
+        assert_eq!(params, Params::default());
+    }
+}
@@ -0,0 +1,422 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::InputSource;
+use crate::params::Params;
+use crate::solution::Solution;
+use crate::visualize::{self, VISUALIZE_FLAG};
+use crate::{bench, Answer, InputError, PartResult, JSON_FLAG};
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+/// type-erased runner of a day: solves the given parts of an input, without verifying them.
+pub type Solver = fn(input: &str, params: &Params, parts: &[u8]) -> Vec<PartResult>;
+
+/// registry entry of a day. every solution registers itself via `advent_of_code::solution!`.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: i16,
+    pub day: u8,
+    pub solve: Solver,
+}
+
+/// command line options of a day binary, e.g. `cargo solve 2022_07 -- --example --part 2`:
+/// - `--input <path>` reads the input from a file, `--input -` or `-` from stdin.
+/// - `--example [name]` reads the default example, or `<day>-<name>.txt`.
+/// - `--part 1|2` runs a single part.
+/// - `--visualize [path]` records the frames the solver emits, see `visualize::Target`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RunOptions {
+    pub source: InputSource,
+    pub part: Option<u8>,
+    pub visualize: Option<visualize::Target>,
+}
+
+impl RunOptions {
+    pub fn from_env() -> Result<Self, String> {
+        Self::parse(env::args().skip(1))
+    }
+
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut options = RunOptions::default();
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-" => options.source = InputSource::Stdin,
+                "--input" => {
+                    options.source = match args.next().as_deref() {
+                        Some("-") => InputSource::Stdin,
+                        Some(path) => InputSource::Path(path.into()),
+                        None => return Err("--input needs a path, or `-` for stdin".into()),
+                    }
+                }
+                "--example" => {
+                    let name = args.next_if(|name| !name.starts_with('-'));
+                    options.source = InputSource::Example(name);
+                }
+                "--part" => {
+                    options.part = match args.next().as_deref() {
+                        Some("1") => Some(1),
+                        Some("2") => Some(2),
+                        _ => return Err("--part needs to be either 1 or 2".into()),
+                    }
+                }
+                VISUALIZE_FLAG => {
+                    let path = args.next_if(|path| !path.starts_with('-'));
+                    options.visualize = Some(visualize::Target::from_arg(path.as_deref()));
+                }
+                JSON_FLAG | bench::BENCH_FLAG => {}
+                _ => return Err(format!("unknown argument `{}`", arg)),
+            }
+        }
+
+        Ok(options)
+    }
+
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|only| only == part)
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        (1..=2).filter(|&part| self.runs_part(part)).collect()
+    }
+}
+
+impl Day {
+    /// runs both parts in-process and verifies them against the stored answers. panics are
+    /// caught per part and reported as failed results, so a broken day does not abort the run.
+    /// a missing or empty input is returned as an error instead, so callers can print its hint.
+    pub fn run(&self) -> Result<Vec<PartResult>, InputError> {
+        let input = crate::try_read_file("inputs", self.year, self.day)?;
+        let params = InputSource::Puzzle.params(self.year, self.day)?;
+        Ok((self.solve)(&input, &params, &[1, 2])
+            .into_iter()
+            .map(PartResult::verify)
+            .collect())
+    }
+}
+
+/// times a single call of `solver`, or benchmarks it repeatedly when `--bench` is passed.
+pub fn measure<T: Into<Answer>>(
+    year: i16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> Option<T>,
+    input: &str,
+) -> PartResult {
+    measure_with(year, day, part, || solver(input))
+}
+
+fn measure_with<T: Into<Answer>>(
+    year: i16,
+    day: u8,
+    part: u8,
+    solver: impl Fn() -> Option<T>,
+) -> PartResult {
+    if bench::bench_mode() {
+        let (answer, stats) = bench::bench(solver);
+        PartResult::benched(year, day, part, answer.map(Into::into), stats)
+    } else {
+        let timer = Instant::now();
+        let answer = solver();
+        let elapsed = timer.elapsed();
+        PartResult::new(year, day, part, answer.map(Into::into), elapsed)
+    }
+}
+
+pub fn run_part<T: Into<Answer>>(
+    year: i16,
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> Option<T>,
+    input: &str,
+) -> PartResult {
+    catch_panic(|| measure(year, day, part, solver, input))
+        .unwrap_or_else(|message| PartResult::failed(year, day, part, message))
+}
+
+/// runs the given parts of a day written as free `part_one` / `part_two` functions.
+pub fn run_parts<A: Into<Answer>, B: Into<Answer>>(
+    year: i16,
+    day: u8,
+    input: &str,
+    parts: &[u8],
+    part_one: impl Fn(&str) -> Option<A>,
+    part_two: impl Fn(&str) -> Option<B>,
+) -> Vec<PartResult> {
+    parts
+        .iter()
+        .map(|&part| match part {
+            1 => run_part(year, day, part, &part_one, input),
+            _ => run_part(year, day, part, &part_two, input),
+        })
+        .collect()
+}
+
+/// parses the input once, then runs the given parts of a `Solution`. every result is passed
+/// through `each` as soon as it is available, e.g. to print it. a parse error fails all parts.
+pub fn run_solution<S: Solution>(
+    year: i16,
+    day: u8,
+    input: &str,
+    params: &Params,
+    parts: &[u8],
+    mut each: impl FnMut(PartResult) -> PartResult,
+) -> Vec<PartResult> {
+    let parsed = catch_panic(|| match bench::bench_mode() {
+        true => {
+            let (parsed, stats) = bench::bench(|| S::parse(input, params));
+            (parsed, stats.median())
+        }
+        false => {
+            let timer = Instant::now();
+            let parsed = S::parse(input, params);
+            (parsed, timer.elapsed())
+        }
+    });
+
+    let (parsed, parse_time) = match parsed {
+        Ok((Ok(parsed), parse_time)) => (parsed, parse_time),
+        Ok((Err(e), _)) => {
+            let message = format!("could not parse input: {}", e);
+            return parts
+                .iter()
+                .map(|&part| each(PartResult::failed(year, day, part, message.clone())))
+                .collect();
+        }
+        Err(message) => {
+            return parts
+                .iter()
+                .map(|&part| each(PartResult::failed(year, day, part, message.clone())))
+                .collect();
+        }
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            let result = catch_panic(|| match part {
+                1 => measure_with(year, day, part, || S::part_one(&parsed)),
+                _ => measure_with(year, day, part, || S::part_two(&parsed)),
+            })
+            .unwrap_or_else(|message| PartResult::failed(year, day, part, message));
+            each(result.with_parse_time(parse_time))
+        })
+        .collect()
+}
+
+thread_local! {
+    /// whether the current thread runs inside `catch_panic`.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// runs `func`, turning a panic into its message. panics caught this way only record their
+/// location instead of printing a backtrace, so that failing days don't flood the output.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    // the hook is process-global, so it is installed once and decides per thread. swapping it
+    // around every call would race with other threads doing the same.
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| match CATCHING.with(Cell::get) {
+            true => {
+                let location = info
+                    .location()
+                    .map(|l| format!("{}:{}", l.file(), l.line()));
+                PANIC_LOCATION.with(|cell| *cell.borrow_mut() = location);
+            }
+            false => default_hook(info),
+        }));
+    });
+
+    let catching = CATCHING.with(|cell| cell.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    CATCHING.with(|cell| cell.set(catching));
+
+    result.map_err(|payload| {
+        let message = panic_message(payload);
+        match PANIC_LOCATION.with(|cell| cell.borrow_mut().take()) {
+            Some(location) => format!("{} (at {})", message, location),
+            None => message,
+        }
+    })
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".into()
+    }
+}
+
+/// total time spent in solvers (medians in bench mode), failed parts are not counted.
+/// the shared parse time of a day is counted once.
+pub fn total_elapsed(results: &[PartResult]) -> Duration {
+    let mut parsed_days = HashSet::new();
+    let parse_time = results
+        .iter()
+        .filter(|result| parsed_days.insert((result.year, result.day)))
+        .filter_map(PartResult::parse_time)
+        .sum::<Duration>();
+
+    results.iter().map(PartResult::elapsed).sum::<Duration>() + parse_time
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::ParseError;
+
+    fn solve(input: &str) -> Option<String> {
+        Some(input.len().to_string())
+    }
+
+    fn explode(_: &str) -> Option<String> {
+        panic!("boom")
+    }
+
+    fn parse(args: &[&str]) -> Result<RunOptions, String> {
+        RunOptions::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_run_options() {
+        assert_eq!(parse(&[]), Ok(RunOptions::default()));
+        assert_eq!(
+            parse(&["--input", "big.txt", "--part", "2"]),
+            Ok(RunOptions {
+                source: InputSource::Path("big.txt".into()),
+                part: Some(2),
+                visualize: None,
+            })
+        );
+        assert_eq!(parse(&["-"]).unwrap().source, InputSource::Stdin);
+        assert_eq!(parse(&["--input", "-"]).unwrap().source, InputSource::Stdin);
+        assert_eq!(
+            parse(&["--example", "--json"]).unwrap().source,
+            InputSource::Example(None)
+        );
+        assert_eq!(
+            parse(&["--example", "small"]).unwrap().source,
+            InputSource::Example(Some("small".into()))
+        );
+        assert_eq!(
+            parse(&["--visualize", "--example"]).unwrap().visualize,
+            Some(visualize::Target::Terminal)
+        );
+        assert_eq!(
+            parse(&["--visualize", "rope.cast"]).unwrap().visualize,
+            Some(visualize::Target::Cast("rope.cast".into()))
+        );
+
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--part", "3"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_run_part() {
+        let result = run_part(2022, 4, 1, solve, "abc");
+        assert_eq!(result.answer, Some("3".into()));
+        assert_eq!(result.error, None);
+        assert!(result.solved);
+    }
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        type Parsed = Vec<usize>;
+        type PartOne = usize;
+        type PartTwo = usize;
+
+        fn parse(input: &str, _params: &Params) -> Result<Self::Parsed, ParseError> {
+            match input.is_empty() {
+                true => Err(ParseError::new("empty input")),
+                false => Ok(input.lines().map(str::len).collect()),
+            }
+        }
+
+        fn part_one(parsed: &Self::Parsed) -> Option<usize> {
+            parsed.iter().max().copied()
+        }
+
+        fn part_two(_: &Self::Parsed) -> Option<usize> {
+            panic!("boom")
+        }
+    }
+
+    #[test]
+    fn test_run_solution() {
+        let mut printed = vec![];
+        let params = Params::new();
+        let results = run_solution::<Lengths>(2022, 4, "a\nabc", &params, &[1, 2], |result| {
+            printed.push(result.part);
+            result
+        });
+
+        assert_eq!(printed, [1, 2]);
+        assert_eq!(results[0].answer, Some(3.into()));
+        assert!(results[0].parse_time().is_some());
+        assert!(results[1].error.as_ref().unwrap().starts_with("boom"));
+    }
+
+    #[test]
+    fn test_run_solution_parse_error() {
+        let results = run_solution::<Lengths>(2022, 4, "", &Params::new(), &[2], |result| result);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].error.as_deref(),
+            Some("could not parse input: empty input")
+        );
+    }
+
+    #[test]
+    fn test_total_elapsed() {
+        let parse_time = Duration::from_millis(5);
+        let results = [
+            PartResult::new(2022, 4, 1, None, Duration::from_millis(1)).with_parse_time(parse_time),
+            PartResult::new(2022, 4, 2, None, Duration::from_millis(2)).with_parse_time(parse_time),
+            PartResult::new(2022, 5, 1, None, Duration::from_millis(3)),
+        ];
+        assert_eq!(total_elapsed(&results), Duration::from_millis(11));
+    }
+
+    #[test]
+    fn test_run_part_catches_panics() {
+        let result = run_part(2022, 4, 2, explode, "abc");
+        assert_eq!(result.answer, None);
+        let error = result.error.as_deref().unwrap_or_default();
+        assert!(error.starts_with("boom (at src/runner.rs:"));
+        assert!(!result.solved);
+        assert_eq!(result.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_catch_panic_in_parallel() {
+        let threads = (0..8)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    (0..50)
+                        .map(|_| run_part(2022, 4, 1, explode, "abc").error.unwrap())
+                        .all(|error| error.starts_with("boom (at src/runner.rs:"))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        assert!(threads.into_iter().all(|thread| thread.join().unwrap()));
+        assert_eq!(catch_panic(|| 1), Ok(1));
+    }
+}
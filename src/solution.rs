@@ -0,0 +1,88 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::params::Params;
+use crate::Answer;
+use std::fmt::{self, Display};
+use std::num::ParseIntError;
+
+/// a day whose input is parsed once and shared by both parts. parsing is timed separately.
+/// register it with `advent_of_code::solution!(<year>, <day>, <type>);`.
+/// `params` holds the puzzle parameters of the input, see `Params`. days that need them keep
+/// their values in `Parsed`.
+pub trait Solution {
+    type Parsed;
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    fn parse(input: &str, params: &Params) -> Result<Self::Parsed, ParseError>;
+    fn part_one(parsed: &Self::Parsed) -> Option<Self::PartOne>;
+    fn part_two(parsed: &Self::Parsed) -> Option<Self::PartTwo>;
+}
+
+/// why an input could not be parsed, optionally pointing at the offending line (1-based).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub line: Option<usize>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+            line: None,
+        }
+    }
+
+    /// attaches the 0-based index of the offending line, as returned by `lines().enumerate()`.
+    pub fn at_line(self, index: usize) -> Self {
+        ParseError {
+            line: Some(index + 1),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseIntError> for ParseError {
+    fn from(e: ParseIntError) -> Self {
+        ParseError::new(e.to_string())
+    }
+}
+
+impl From<&str> for ParseError {
+    fn from(message: &str) -> Self {
+        ParseError::new(message)
+    }
+}
+
+impl From<String> for ParseError {
+    fn from(message: String) -> Self {
+        ParseError::new(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_display() {
+        assert_eq!(ParseError::new("empty input").to_string(), "empty input");
+
+        let error = ParseError::from("x".parse::<u32>().unwrap_err()).at_line(2);
+        assert_eq!(error.to_string(), "line 3: invalid digit found in string");
+    }
+}
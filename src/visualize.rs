@@ -0,0 +1,362 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::helpers::grid::Grid;
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+
+pub const VISUALIZE_FLAG: &str = "--visualize";
+/// cells that are cropped away around a frame.
+pub const BACKGROUND: char = '.';
+
+/// where the frames of `--visualize [path]` go.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// animates the frames in the terminal.
+    Terminal,
+    /// an asciinema cast file, for `--visualize <path>.cast`.
+    Cast(PathBuf),
+    /// a directory with one PPM image per frame, for any other path.
+    Ppm(PathBuf),
+}
+
+impl Target {
+    pub fn from_arg(path: Option<&str>) -> Self {
+        match path {
+            None => Target::Terminal,
+            Some(path) if path.ends_with(".cast") => Target::Cast(path.into()),
+            Some(path) => Target::Ppm(path.into()),
+        }
+    }
+}
+
+/// a snapshot of a simulation, one string per row.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Frame {
+    pub rows: Vec<String>,
+}
+
+impl Frame {
+    pub fn from_grid<T>(grid: &Grid<T>, render: impl FnMut(&T) -> char) -> Self {
+        Frame {
+            rows: grid.render(render).lines().map(String::from).collect(),
+        }
+    }
+
+    /// draws sparse `((row, column), cell)` points onto the background, spanning their bounding
+    /// box. later points are drawn over earlier ones.
+    pub fn from_points(points: impl IntoIterator<Item = ((i64, i64), char)>) -> Self {
+        let points = points.into_iter().collect::<Vec<_>>();
+        let (Some(top), Some(bottom), Some(left), Some(right)) = (
+            points.iter().map(|((row, _), _)| *row).min(),
+            points.iter().map(|((row, _), _)| *row).max(),
+            points.iter().map(|((_, column), _)| *column).min(),
+            points.iter().map(|((_, column), _)| *column).max(),
+        ) else {
+            return Frame::default();
+        };
+
+        let width = (right - left + 1) as usize;
+        let mut cells = vec![vec![BACKGROUND; width]; (bottom - top + 1) as usize];
+        for ((row, column), cell) in points {
+            cells[(row - top) as usize][(column - left) as usize] = cell;
+        }
+
+        Frame {
+            rows: cells
+                .into_iter()
+                .map(|row| row.into_iter().collect())
+                .collect(),
+        }
+    }
+
+    /// crops the frame to the bounding box of the cells that are not `BACKGROUND` or blank.
+    pub fn cropped(self) -> Self {
+        let is_set = |c: char| c != BACKGROUND && c != ' ';
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| row.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        let set_rows = rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| row.iter().any(|&c| is_set(c)))
+            .map(|(index, _)| index);
+        let (Some(top), Some(bottom)) = (set_rows.clone().min(), set_rows.max()) else {
+            return Frame::default();
+        };
+
+        let set_columns = rows
+            .iter()
+            .flat_map(|row| row.iter().enumerate().filter(|(_, &c)| is_set(c)))
+            .map(|(index, _)| index);
+        let left = set_columns.clone().min().unwrap_or_default();
+        let right = set_columns.max().unwrap_or_default();
+
+        Frame {
+            rows: rows[top..=bottom]
+                .iter()
+                .map(|row| row[left..=right.min(row.len() - 1)].iter().collect())
+                .collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.rows.join("\n"))
+    }
+}
+
+/// starts recording the frames emitted by solvers.
+#[cfg(feature = "visualize")]
+pub fn start(target: Target) -> Result<(), String> {
+    recorder::start(target)
+}
+
+#[cfg(not(feature = "visualize"))]
+pub fn start(_target: Target) -> Result<(), String> {
+    Err(format!(
+        "{} is not available in this build, run it with `--features visualize`.",
+        VISUALIZE_FLAG
+    ))
+}
+
+/// whether frames are recorded. solvers can check it before doing extra work for frames.
+pub fn enabled() -> bool {
+    #[cfg(feature = "visualize")]
+    return recorder::enabled();
+    #[cfg(not(feature = "visualize"))]
+    false
+}
+
+/// records a frame, `frame` is only called when `--visualize` is active.
+pub fn emit(frame: impl FnOnce() -> Frame) {
+    #[cfg(feature = "visualize")]
+    recorder::emit(frame);
+    #[cfg(not(feature = "visualize"))]
+    drop(frame);
+}
+
+/// writes out what is still buffered, e.g. the cast file.
+pub fn finish() -> Result<(), String> {
+    #[cfg(feature = "visualize")]
+    return recorder::finish();
+    #[cfg(not(feature = "visualize"))]
+    Ok(())
+}
+
+#[cfg(feature = "visualize")]
+mod recorder {
+    use super::{Frame, Target, BACKGROUND};
+    use std::fs;
+    use std::io::{self, Write};
+    use std::path::Path;
+    use std::sync::Mutex;
+    use std::thread;
+    use std::time::Duration;
+
+    const FRAME_DELAY: Duration = Duration::from_millis(40);
+    /// edge length of a cell in PPM images, in pixels.
+    const PPM_SCALE: usize = 4;
+    const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+    struct Recorder {
+        target: Target,
+        frames: Vec<Frame>,
+        count: usize,
+    }
+
+    static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+
+    pub fn start(target: Target) -> Result<(), String> {
+        if let Target::Ppm(dir) = &target {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("could not create \"{}\": {}", dir.display(), e))?;
+        }
+
+        *RECORDER.lock().unwrap() = Some(Recorder {
+            target,
+            frames: vec![],
+            count: 0,
+        });
+        Ok(())
+    }
+
+    pub fn enabled() -> bool {
+        RECORDER.lock().unwrap().is_some()
+    }
+
+    pub fn emit(frame: impl FnOnce() -> Frame) {
+        let mut recorder = RECORDER.lock().unwrap();
+        let Some(state) = recorder.as_mut() else {
+            return;
+        };
+
+        let frame = frame();
+        let written = match &state.target {
+            Target::Terminal => {
+                let mut stdout = io::stdout().lock();
+                let written =
+                    writeln!(stdout, "{}{}", CLEAR_SCREEN, frame).and_then(|_| stdout.flush());
+                thread::sleep(FRAME_DELAY);
+                written
+            }
+            Target::Cast(_) => {
+                state.frames.push(frame);
+                Ok(())
+            }
+            Target::Ppm(dir) => {
+                let path = dir.join(format!("frame_{:05}.ppm", state.count));
+                fs::write(path, ppm(&frame))
+            }
+        };
+        state.count += 1;
+
+        // a broken output should not abort the solver, the remaining frames are dropped.
+        if let Err(e) = written {
+            eprintln!("Stopped visualizing: {}", e);
+            *recorder = None;
+        }
+    }
+
+    pub fn finish() -> Result<(), String> {
+        match RECORDER.lock().unwrap().take() {
+            Some(Recorder {
+                target: Target::Cast(path),
+                frames,
+                ..
+            }) => write_cast(&path, &frames),
+            _ => Ok(()),
+        }
+    }
+
+    fn write_cast(path: &Path, frames: &[Frame]) -> Result<(), String> {
+        fs::write(path, cast(frames))
+            .map_err(|e| format!("could not write \"{}\": {}", path.display(), e))
+    }
+
+    /// an asciinema v2 recording: a header, then one `[time, "o", output]` event per frame.
+    pub(super) fn cast(frames: &[Frame]) -> String {
+        let width = frames
+            .iter()
+            .map(Frame::width)
+            .max()
+            .unwrap_or_default()
+            .max(1);
+        let height = frames.iter().map(Frame::height).max().unwrap_or_default() + 1;
+
+        let header = serde_json::json!({ "version": 2, "width": width, "height": height });
+        let events = frames.iter().enumerate().map(|(index, frame)| {
+            let time = index as f64 * FRAME_DELAY.as_secs_f64();
+            let output = format!("{}{}\r\n", CLEAR_SCREEN, frame.rows.join("\r\n"));
+            serde_json::json!([time, "o", output]).to_string()
+        });
+
+        std::iter::once(header.to_string())
+            .chain(events)
+            .map(|line| line + "\n")
+            .collect()
+    }
+
+    /// a binary PPM image, every cell is a square of `PPM_SCALE` pixels.
+    pub(super) fn ppm(frame: &Frame) -> Vec<u8> {
+        let (width, height) = (frame.width() * PPM_SCALE, frame.height() * PPM_SCALE);
+        let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+
+        for row in &frame.rows {
+            let mut pixels = row.chars().map(colour).collect::<Vec<_>>();
+            pixels.resize(frame.width(), colour(BACKGROUND));
+            let line = pixels
+                .iter()
+                .flat_map(|pixel| pixel.repeat(PPM_SCALE))
+                .collect::<Vec<_>>();
+            for _ in 0..PPM_SCALE {
+                image.extend(&line);
+            }
+        }
+
+        image
+    }
+
+    fn colour(cell: char) -> [u8; 3] {
+        match cell {
+            BACKGROUND | ' ' => [20, 20, 40],
+            '#' => [150, 150, 150],
+            'o' => [230, 190, 80],
+            '+' => [230, 70, 70],
+            // any other cell gets a stable, bright colour of its own.
+            cell => {
+                let hash = (cell as u32).wrapping_mul(2654435761);
+                [hash >> 24, hash >> 16, hash >> 8].map(|channel| channel as u8 | 0x60)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_from_arg() {
+        assert_eq!(Target::from_arg(None), Target::Terminal);
+        assert_eq!(
+            Target::from_arg(Some("day14.cast")),
+            Target::Cast("day14.cast".into())
+        );
+        assert_eq!(
+            Target::from_arg(Some("frames")),
+            Target::Ppm("frames".into())
+        );
+    }
+
+    #[test]
+    fn test_frame_from_points() {
+        let frame = Frame::from_points([((0, 0), 'T'), ((-1, 2), 'H'), ((0, 1), '1')]);
+        assert_eq!(frame.to_string(), "..H\nT1.");
+        assert_eq!((frame.width(), frame.height()), (3, 2));
+        assert_eq!(Frame::from_points([]), Frame::default());
+    }
+
+    #[test]
+    fn test_frame_cropped() {
+        let grid = ".....\n..#..\n.#o..\n.....".parse::<Grid<char>>().unwrap();
+        let frame = Frame::from_grid(&grid, |&c| c).cropped();
+        assert_eq!(frame.rows, [".#", "#o"]);
+        assert_eq!(Frame::from_grid(&grid, |_| '.').cropped(), Frame::default());
+    }
+
+    #[cfg(feature = "visualize")]
+    #[test]
+    fn test_recordings() {
+        let frames = [
+            Frame::from_points([((0, 0), '#')]),
+            Frame::from_points([((0, 0), 'o'), ((1, 1), '#')]),
+        ];
+
+        let cast = recorder::cast(&frames);
+        let lines = cast.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], r#"{"height":3,"version":2,"width":2}"#);
+        assert_eq!(lines[2], r#"[0.04,"o","\u001b[2J\u001b[Ho.\r\n.#\r\n"]"#);
+
+        let image = recorder::ppm(&frames[1]);
+        assert!(image.starts_with(b"P6\n8 8\n255\n"));
+        assert_eq!(image.len(), "P6\n8 8\n255\n".len() + 8 * 8 * 3);
+    }
+}